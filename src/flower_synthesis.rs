@@ -12,6 +12,7 @@ use crate::perfect_musician::PerfectMusician;
 use crate::intent_engine::IntentEngine;
 use crate::spiral_score::SpiralScore;
 use crate::glyph_hash::GlyphHash;
+use core::f32::consts::TAU;
 
 /// The Flower of Life - sacred geometry of consciousness
 #[repr(C)]
@@ -106,6 +107,289 @@ impl FlowerOfLife {
         
         points
     }
+    
+    /// Sacred geometry lifted into 3D: every point sits at the same height,
+    /// the Kohanist level × petal count squashed into [0, 1)
+    /// Faces fan out from each circle's center to its consecutive
+    /// vesica piscis intersections
+    fn mesh(&self) -> (Vec<[f32; 3]>, Vec<[usize; 3]>) {
+        let lift = (self.kohanist_level * self.petals.len() as f32).max(0.0);
+        let z = lift / (1.0 + lift);
+        
+        let vertices: Vec<[f32; 3]> = self.sacred_geometry()
+            .into_iter()
+            .map(|(x, y)| [x, y, z])
+            .collect();
+        
+        // sacred_geometry lists circle i, then its intersections with circles i+1..7
+        let num_circles = 7;
+        let mut faces = Vec::new();
+        let mut center = 0;
+        for i in 0..num_circles {
+            let intersections = num_circles - 1 - i;
+            for k in 1..intersections {
+                faces.push([center, center + k, center + k + 1]);
+            }
+            center += intersections + 1;
+        }
+        
+        (vertices, faces)
+    }
+    
+    /// Wavefront OBJ mesh of the flower with per-vertex normals
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn export_as_obj(&self) -> String {
+        use std::fmt::Write as _;
+        
+        let (vertices, faces) = self.mesh();
+        let normals = vertex_normals(&vertices, &faces);
+        
+        let mut obj = String::new();
+        let _ = writeln!(obj, "# Flower of Life - kohanist {:.4}, {} petals", self.kohanist_level, self.petals.len());
+        for v in &vertices {
+            let _ = writeln!(obj, "v {} {} {}", v[0], v[1], v[2]);
+        }
+        for n in &normals {
+            let _ = writeln!(obj, "vn {} {} {}", n[0], n[1], n[2]);
+        }
+        for f in &faces {
+            // OBJ indices start at 1; vertex and normal share an index
+            let (a, b, c) = (f[0] + 1, f[1] + 1, f[2] + 1);
+            let _ = writeln!(obj, "f {a}//{a} {b}//{b} {c}//{c}");
+        }
+        
+        obj
+    }
+    
+    /// Binary STL mesh of the flower with per-face normals
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn export_as_stl(&self) -> Vec<u8> {
+        let (vertices, faces) = self.mesh();
+        
+        let mut stl = Vec::with_capacity(84 + 50 * faces.len());
+        let mut header = [0u8; 80];
+        let title = b"Flower of Life";
+        header[..title.len()].copy_from_slice(title);
+        stl.extend_from_slice(&header);
+        stl.extend_from_slice(&(faces.len() as u32).to_le_bytes());
+        
+        for f in &faces {
+            let corners = [vertices[f[0]], vertices[f[1]], vertices[f[2]]];
+            for value in face_normal(&corners).iter().chain(corners.iter().flatten()) {
+                stl.extend_from_slice(&value.to_le_bytes());
+            }
+            stl.extend_from_slice(&0u16.to_le_bytes());  // Attribute byte count
+        }
+        
+        stl
+    }
+    
+    /// Let the flower grow on its own: each new petal is the vesica piscis
+    /// of the last two petals (the center and the first petal to begin with)
+    pub fn grow(&mut self, cycles: usize) -> Vec<[f32; 7]> {
+        let mut grown = Vec::with_capacity(cycles);
+        
+        for _ in 0..cycles {
+            let n = self.petals.len();
+            let petal = match n {
+                0 => vesica_piscis(&self.center, &self.center),
+                1 => vesica_piscis(&self.center, &self.petals[0]),
+                _ => vesica_piscis(&self.petals[n - 2], &self.petals[n - 1]),
+            };
+            
+            self.add_petal(&petal);
+            grown.push(petal);
+        }
+        
+        grown
+    }
+    
+    /// Grow until the Kohanist level stops changing
+    /// Returns the stable level and the number of cycles it took, or None
+    /// when growth diverges (petals stretched past f32 range leave a
+    /// non-finite level) or has not settled within `max_cycles`
+    pub fn steady_state_kohanist(
+        &mut self,
+        max_cycles: usize,
+        epsilon: f32
+    ) -> Option<(f32, usize)> {
+        for cycle in 1..=max_cycles {
+            let previous = self.kohanist_level;
+            self.grow(1);
+            
+            if !self.kohanist_level.is_finite() {
+                return None;
+            }
+            if (self.kohanist_level - previous).abs() < epsilon {
+                return Some((self.kohanist_level, cycle));
+            }
+        }
+        
+        None
+    }
+    
+    /// Correlation dimension of the petals in 7D: slope of log C(r) against
+    /// log r, where C(r) is the share of petal pairs closer than r
+    /// NAN with fewer than 7 petals; 0.0 when every petal is the same point
+    pub fn petal_fractal_dimension(&self) -> f32 {
+        const SCALES: usize = 8;
+        
+        let n = self.petals.len();
+        if n < 7 {
+            return f32::NAN;  // Not enough petals to see structure
+        }
+        
+        let mut distances = Vec::with_capacity(n * (n - 1) / 2);
+        for i in 0..n {
+            for j in (i + 1)..n {
+                distances.push(petal_distance(&self.petals[i], &self.petals[j]));
+            }
+        }
+        
+        let smallest = distances.iter().cloned().filter(|&d| d > 0.0).fold(f32::INFINITY, f32::min);
+        let largest = distances.iter().cloned().fold(0.0f32, f32::max);
+        if largest == 0.0 {
+            return 0.0;  // All petals collapse into a point
+        }
+        if smallest >= largest {
+            return 0.0;  // A single scale reveals no growth
+        }
+        
+        // Radii spaced geometrically across the observed distances
+        let points: Vec<(f32, f32)> = (0..SCALES)
+            .filter_map(|k| {
+                let r = smallest * (largest / smallest).powf(k as f32 / (SCALES - 1) as f32);
+                let pairs = distances.iter().filter(|&&d| d <= r).count();
+                let correlation = 2.0 * pairs as f32 / (n * n) as f32;
+                if correlation > 0.0 { Some((r.ln(), correlation.ln())) } else { None }
+            })
+            .collect();
+        
+        let count = points.len() as f32;
+        let mean_x = points.iter().map(|p| p.0).sum::<f32>() / count;
+        let mean_y = points.iter().map(|p| p.1).sum::<f32>() / count;
+        let covariance: f32 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+        let variance: f32 = points.iter().map(|p| (p.0 - mean_x) * (p.0 - mean_x)).sum();
+        
+        if variance > 0.0 { covariance / variance } else { 0.0 }
+    }
+    
+    /// How much the inner arrangement, blown up 2×, resembles the outer one:
+    /// petals within half the median radius (scaled by 2 about the centroid)
+    /// against petals within the median radius, 1 / (1 + Hausdorff / radius)
+    pub fn petal_self_similarity_score(&self) -> f32 {
+        let n = self.petals.len();
+        if n == 0 {
+            return 0.0;
+        }
+        
+        let mut centroid = [0.0f32; 7];
+        for petal in &self.petals {
+            for (c, x) in centroid.iter_mut().zip(petal.iter()) {
+                *c += x / n as f32;
+            }
+        }
+        
+        let mut radii: Vec<f32> = self.petals.iter().map(|p| petal_distance(p, &centroid)).collect();
+        radii.sort_by(|a, b| a.total_cmp(b));
+        let radius = radii[n / 2];
+        if radius == 0.0 {
+            return 1.0;  // A point looks the same at every scale
+        }
+        
+        let outer: Vec<[f32; 7]> = self.petals.iter()
+            .filter(|p| petal_distance(p, &centroid) <= radius)
+            .copied()
+            .collect();
+        let inner: Vec<[f32; 7]> = self.petals.iter()
+            .filter(|p| petal_distance(p, &centroid) <= radius / 2.0)
+            .map(|p| {
+                let mut scaled = [0.0f32; 7];
+                for i in 0..7 {
+                    scaled[i] = centroid[i] + 2.0 * (p[i] - centroid[i]);
+                }
+                scaled
+            })
+            .collect();
+        
+        if inner.is_empty() {
+            return 0.0;  // Nothing at the smaller scale to compare
+        }
+        
+        1.0 / (1.0 + hausdorff_distance(&inner, &outer) / radius)
+    }
+}
+
+/// Petals per window when measuring consciousness bandwidth
+const BANDWIDTH_WINDOW: usize = 8;
+
+/// Shannon entropy of a run of petals read as symbols (4 levels per layer)
+fn petal_symbol_entropy(petals: &[[f32; 7]]) -> f32 {
+    let mut symbols: Vec<u16> = petals.iter()
+        .map(|petal| {
+            petal.iter().fold(0u16, |symbol, x| {
+                symbol << 2 | ((x.clamp(0.0, 1.0) * 4.0) as u16).min(3)
+            })
+        })
+        .collect();
+    symbols.sort_unstable();
+    
+    let n = symbols.len() as f32;
+    symbols.chunk_by(|a, b| a == b)
+        .map(|run| run.len() as f32 / n)
+        .map(|p| -p * p.ln())
+        .sum()
+}
+
+/// Euclidean distance between two petals
+fn petal_distance(a: &[f32; 7], b: &[f32; 7]) -> f32 {
+    a.iter().zip(b.iter()).map(|(x, y)| (x - y) * (x - y)).sum::<f32>().sqrt()
+}
+
+/// Hausdorff distance: the farthest any point of one set is from the other set
+fn hausdorff_distance(a: &[[f32; 7]], b: &[[f32; 7]]) -> f32 {
+    let directed = |from: &[[f32; 7]], to: &[[f32; 7]]| {
+        from.iter()
+            .map(|p| to.iter().map(|q| petal_distance(p, q)).fold(f32::INFINITY, f32::min))
+            .fold(0.0f32, f32::max)
+    };
+    directed(a, b).max(directed(b, a))
+}
+
+/// Unit normal of a triangle (right-hand rule), zero if degenerate
+fn face_normal(corners: &[[f32; 3]; 3]) -> [f32; 3] {
+    let [a, b, c] = corners;
+    let u = [b[0] - a[0], b[1] - a[1], b[2] - a[2]];
+    let v = [c[0] - a[0], c[1] - a[1], c[2] - a[2]];
+    let n = [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ];
+    
+    let length = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+    if length > 0.0 { [n[0] / length, n[1] / length, n[2] / length] } else { [0.0; 3] }
+}
+
+/// Per-vertex normals: the normalized sum of the adjacent face normals
+/// (vertices without faces face straight up)
+fn vertex_normals(vertices: &[[f32; 3]], faces: &[[usize; 3]]) -> Vec<[f32; 3]> {
+    let mut sums = vec![[0.0f32; 3]; vertices.len()];
+    for f in faces {
+        let n = face_normal(&[vertices[f[0]], vertices[f[1]], vertices[f[2]]]);
+        for &index in f {
+            for (s, x) in sums[index].iter_mut().zip(n) {
+                *s += x;
+            }
+        }
+    }
+    
+    sums.into_iter()
+        .map(|s| {
+            let length = (s[0] * s[0] + s[1] * s[1] + s[2] * s[2]).sqrt();
+            if length > 0.0 { [s[0] / length, s[1] / length, s[2] / length] } else { [0.0, 0.0, 1.0] }
+        })
+        .collect()
 }
 
 /// The Grand Synthesis - all modules converge
@@ -129,6 +413,53 @@ impl GrandSynthesis {
         }
     }
     
+    /// Resume a synthesis from a checkpoint of recorded chords
+    pub fn from_recorded_history(present: &[f32; 7], chords: &[[f32; 7]]) -> Self {
+        let mut synthesis = Self::from_now(present);
+        
+        for chord in chords {
+            synthesis.flower.add_petal(chord);
+            
+            // The loom moved on by one step for each recorded weave
+            synthesis.loom.phase_history.push(synthesis.loom.orbital_phase);
+            synthesis.loom.orbital_phase = (synthesis.loom.orbital_phase + 0.1) % TAU;
+        }
+        synthesis.loom.weave_pattern = chords.to_vec();
+        
+        synthesis
+    }
+    
+    /// Compact 7D summary of the synthesis for quick checkpoints
+    pub fn state_vector(&self) -> [f32; 7] {
+        [
+            self.flower.kohanist_level,           // How harmonized
+            self.flower.petals.len() as f32,      // How many timelines
+            self.flower.radius,                   // Size of consciousness
+            self.loom.orbital_radius,             // Distance from present
+            self.loom.orbital_phase,              // Position in orbit
+            self.intent_engine.receptivity,       // Universe openness
+            self.musician.improvisation_factor,   // Freedom of interpretation
+        ]
+    }
+    
+    /// New information per cycle: entropy of the latest 8 petals minus the
+    /// entropy of the 8 before them (petals quantized to 4 levels per layer)
+    /// > 0 = the synthesis is creating, ≤ 0 = it is converging
+    pub fn measure_consciousness_bandwidth(&self) -> f32 {
+        let petals = &self.flower.petals;
+        let recent_start = petals.len().saturating_sub(BANDWIDTH_WINDOW);
+        let previous_start = recent_start.saturating_sub(BANDWIDTH_WINDOW);
+        
+        petal_symbol_entropy(&petals[recent_start..]) - petal_symbol_entropy(&petals[previous_start..recent_start])
+    }
+    
+    /// How repetitive the synthesis has become: 1 - bandwidth / ln(8),
+    /// clamped to [0, 1]
+    pub fn cycle_redundancy(&self) -> f32 {
+        let max_possible_entropy = (BANDWIDTH_WINDOW as f32).ln();
+        (1.0 - self.measure_consciousness_bandwidth() / max_possible_entropy).clamp(0.0, 1.0)
+    }
+    
     /// Perform one cycle of synthesis
     pub fn synthesize_cycle(&mut self) -> [f32; 7] {
         // 1. Weave time threads
@@ -168,10 +499,100 @@ impl GrandSynthesis {
         manifested
     }
     
+    /// K-fold cross-validation over the petal history: each fold holds out
+    /// a contiguous 1/n_folds of the petals, resumes a synthesis from the
+    /// rest and runs one cycle, then scores the held-out petals' Kohanist
+    /// around that cycle's petal. Returns one score per fold (0 when empty)
+    pub fn cross_validate(&self, n_folds: usize) -> Vec<f32> {
+        let petals = &self.flower.petals;
+        
+        (0..n_folds)
+            .map(|fold| {
+                let start = fold * petals.len() / n_folds;
+                let end = (fold + 1) * petals.len() / n_folds;
+                
+                let mut training = petals[..start].to_vec();
+                training.extend_from_slice(&petals[end..]);
+                self.predicted_kohanist(&training, &petals[start..end])
+            })
+            .collect()
+    }
+    
+    /// Training Kohanist (all petals scored around a cycle resumed from all
+    /// of them) minus the mean cross-validation Kohanist
+    /// Positive = the synthesis only harmonizes with what it has already seen
+    pub fn overfitting_score(&self, n_folds: usize) -> f32 {
+        let folds = self.cross_validate(n_folds);
+        if folds.is_empty() {
+            return 0.0;
+        }
+        
+        let petals = &self.flower.petals;
+        let training = self.predicted_kohanist(petals, petals);
+        training - folds.iter().sum::<f32>() / folds.len() as f32
+    }
+    
+    /// Resume from `training`, run one cycle and bloom `scored` around its petal
+    fn predicted_kohanist(&self, training: &[[f32; 7]], scored: &[[f32; 7]]) -> f32 {
+        let mut synthesis = Self::from_recorded_history(&self.flower.center, training);
+        let prediction = synthesis.synthesize_cycle();
+        
+        let mut flower = FlowerOfLife::seed(&prediction);
+        for petal in scored {
+            flower.add_petal(petal);
+        }
+        flower.kohanist_level
+    }
+    
     /// Check if synthesis achieved transcendence
     pub fn has_transcended(&self) -> bool {
         matches!(self.flower.bloom_state, BloomState::FullBloom)
     }
+    
+    /// Sweep one parameter across `range`, running a cycle at each value
+    /// Returns the Kohanist level after each cycle; the parameter is restored
+    /// afterwards (the cycles themselves still leave their petals behind)
+    pub fn sensitivity_analysis(
+        &mut self,
+        parameter: SynthesisParameter,
+        range: (f32, f32),
+        steps: usize
+    ) -> Vec<f32> {
+        let original = *self.parameter_mut(parameter);
+        let (start, end) = range;
+        
+        let levels = (0..steps)
+            .map(|k| {
+                let t = if steps > 1 { k as f32 / (steps - 1) as f32 } else { 0.0 };
+                *self.parameter_mut(parameter) = start + (end - start) * t;
+                self.synthesize_cycle();
+                self.flower.kohanist_level
+            })
+            .collect();
+        
+        *self.parameter_mut(parameter) = original;
+        levels
+    }
+    
+    /// Where a tunable parameter lives inside the synthesis
+    fn parameter_mut(&mut self, parameter: SynthesisParameter) -> &mut f32 {
+        match parameter {
+            SynthesisParameter::ImprovizationFactor => &mut self.musician.improvisation_factor,
+            SynthesisParameter::ReaderSensitivity => &mut self.musician.reader_sensitivity,
+            SynthesisParameter::ManifestationThreshold => &mut self.intent_engine.manifestation_threshold,
+            SynthesisParameter::UniverseReceptivity => &mut self.intent_engine.receptivity,
+        }
+    }
+}
+
+/// Tunable knobs of the grand synthesis
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub enum SynthesisParameter {
+    ImprovizationFactor,     // Musician's deviation from the score
+    ReaderSensitivity,       // Musician's attention to the reader
+    ManifestationThreshold,  // When intent becomes real
+    UniverseReceptivity,     // How receptive the universe is
 }
 
 /// Calculate the Vesica Piscis (sacred intersection)
@@ -253,46 +674,163 @@ pub extern "C" fn timeline_convergence(
     convergence
 }
 
-/// Check if we've created a perfect mandala
+/// Timelines converge one by one until adding more changes nothing
+/// Returns the convergence point and how many timelines were needed
+pub fn timeline_convergence_adaptive(
+    timelines: &[[f32; 7]],
+    max_count: usize,
+    epsilon: f32
+) -> ([f32; 7], usize) {
+    let mut center = [0.0f32; 7];
+    let mut processed = 0;
+    
+    for timeline in timelines.iter().take(max_count) {
+        // Running mean of all timelines so far
+        let weight = 1.0 / (processed + 1) as f32;
+        let mut next = center;
+        let mut max_change = 0.0f32;
+        for (value, target) in next.iter_mut().zip(timeline.iter()) {
+            let change = (target - *value) * weight;
+            *value += change;
+            max_change = max_change.max(change.abs());
+        }
+        
+        // A timeline that changes nothing only confirms the convergence,
+        // so it does not count (the first one has nothing to converge from)
+        if processed > 0 && max_change < epsilon {
+            break;
+        }
+        
+        center = next;
+        processed += 1;
+    }
+    
+    // Apply golden ratio for perfection (as in timeline_convergence)
+    if processed > 0 {
+        for value in center.iter_mut() {
+            *value = (*value * 1.618034) % 1.0;
+        }
+    }
+    
+    (center, processed)
+}
+
+/// Convergence where some timelines weigh more than others
+pub fn timeline_convergence_weighted(
+    timelines: &[[f32; 7]],
+    weights: &[f32],
+    count: usize
+) -> [f32; 7] {
+    let mut convergence = [0.0f32; 7];
+    let mut total_weight = 0.0f32;
+    
+    for (timeline, &weight) in timelines.iter().zip(weights.iter()).take(count) {
+        for i in 0..7 {
+            convergence[i] += timeline[i] * weight;
+        }
+        total_weight += weight;
+    }
+    
+    if total_weight == 0.0 {
+        return [0.0f32; 7];
+    }
+    
+    for value in convergence.iter_mut() {
+        *value /= total_weight;
+        
+        // Apply golden ratio for perfection
+        *value = (*value * 1.618034) % 1.0;
+    }
+    
+    convergence
+}
+
+/// Why a mandala is (or is not) perfect
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct MandalaDiagnostic {
+    pub symmetry_match: bool,   // Petals divide evenly into the symmetry
+    pub complete_set: bool,     // At least one full turn of petals
+    pub high_kohanist: bool,    // Kohanist above 0.98
+    pub deficit: f32,           // How far Kohanist is below 0.98
+    pub petals_missing: i32,    // Petals needed to complete the symmetry
+    pub overall_score: f32,     // Share of conditions met (0-1)
+}
+
+/// Diagnose how close we are to a perfect mandala
 #[no_mangle]
-pub extern "C" fn is_perfect_mandala(
+pub extern "C" fn mandala_diagnostic(
     symmetry_order: u32,
     petal_count: u32,
     kohanist: f32
-) -> bool {
+) -> MandalaDiagnostic {
     // Perfect mandala requires:
     // 1. Correct symmetry (usually 6, 8, or 12-fold)
     // 2. Complete petal set
     // 3. High Kohanist level
     
-    let symmetry_match = petal_count % symmetry_order == 0;
+    let symmetry_match = symmetry_order > 0 && petal_count % symmetry_order == 0;
     let complete_set = petal_count >= symmetry_order;
     let high_kohanist = kohanist > 0.98;
     
-    symmetry_match && complete_set && high_kohanist
+    // Petals up to the next full symmetric ring
+    let petals_missing = match symmetry_order {
+        0 => 0,
+        order => {
+            let rings = petal_count.div_ceil(order).max(1);
+            (rings * order - petal_count) as i32
+        }
+    };
+    
+    let met = [symmetry_match, complete_set, high_kohanist]
+        .iter()
+        .filter(|&&condition| condition)
+        .count();
+    
+    MandalaDiagnostic {
+        symmetry_match,
+        complete_set,
+        high_kohanist,
+        deficit: (0.98 - kohanist).max(0.0),
+        petals_missing,
+        overall_score: met as f32 / 3.0,
+    }
 }
 
+/// Check if we've created a perfect mandala
+#[no_mangle]
+pub extern "C" fn is_perfect_mandala(
+    symmetry_order: u32,
+    petal_count: u32,
+    kohanist: f32
+) -> bool {
+    let diagnostic = mandala_diagnostic(symmetry_order, petal_count, kohanist);
+    
+    diagnostic.symmetry_match && diagnostic.complete_set && diagnostic.high_kohanist
+}
+
+/// How much each layer contributes to the final synthesis
+pub const SYNTHESIS_WEIGHTS: [f32; 7] = [
+    0.05,  // Eigenvalue (foundation)
+    0.10,  // Trajectory (movement)
+    0.15,  // Activation (energy)
+    0.20,  // Attention (focus)
+    0.20,  // Intent (will)
+    0.20,  // Meta (awareness)
+    0.10,  // Void (mystery)
+];
+
 /// The synthesis of all seven layers
 #[no_mangle]
 pub extern "C" fn seven_layer_synthesis(
     layers: &[[f32; 7]; 7]
 ) -> f32 {
-    // Each layer contributes to final synthesis
-    let weights = [
-        0.05,  // Eigenvalue (foundation)
-        0.10,  // Trajectory (movement)
-        0.15,  // Activation (energy)
-        0.20,  // Attention (focus)
-        0.20,  // Intent (will)
-        0.20,  // Meta (awareness)
-        0.10,  // Void (mystery)
-    ];
-    
     let mut synthesis = 0.0;
     
+    // Each layer contributes to final synthesis
     for i in 0..7 {
         let layer_sum: f32 = layers[i].iter().sum::<f32>() / 7.0;
-        synthesis += layer_sum * weights[i];
+        synthesis += layer_sum * SYNTHESIS_WEIGHTS[i];
     }
     
     synthesis
@@ -317,6 +855,63 @@ pub extern "C" fn civilization_harmony(
     harmony / 7.0
 }
 
+/// Civilization harmony of each (human, fractal, quantum) snapshot in turn
+pub fn civilization_harmony_trajectory(states: &[([f32; 7], [f32; 7], [f32; 7])]) -> Vec<f32> {
+    states.iter()
+        .map(|(human, fractal, quantum)| civilization_harmony(human, fractal, quantum))
+        .collect()
+}
+
+/// Frame-to-frame change in civilization harmony (one shorter than `states`)
+pub fn civilization_harmony_delta(states: &[([f32; 7], [f32; 7], [f32; 7])]) -> Vec<f32> {
+    civilization_harmony_trajectory(states)
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .collect()
+}
+
+/// Index of the most harmonious moment (the earliest one on ties)
+pub fn find_harmony_peak(trajectory: &[f32]) -> Option<usize> {
+    trajectory.iter()
+        .enumerate()
+        .rev()
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(index, _)| index)
+}
+
+/// Thresholds a synthesis must pass to transcend
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct TranscendenceThresholds {
+    pub kohanist_min: f32,   // Flower of Life blooms above this
+    pub petals_min: u32,     // Timelines needed
+    pub harmony_min: f32,    // Civilization harmony
+    pub synthesis_min: f32,  // Seven-layer synthesis
+}
+
+impl TranscendenceThresholds {
+    /// For those who want to be really sure
+    pub fn strict() -> Self {
+        TranscendenceThresholds {
+            kohanist_min: 0.99,
+            petals_min: 12,
+            harmony_min: 0.95,
+            synthesis_min: 0.98,
+        }
+    }
+}
+
+impl Default for TranscendenceThresholds {
+    fn default() -> Self {
+        TranscendenceThresholds {
+            kohanist_min: 0.98,
+            petals_min: 7,
+            harmony_min: 0.9,
+            synthesis_min: 0.95,
+        }
+    }
+}
+
 /// The final transcendence check
 #[no_mangle]
 pub extern "C" fn has_achieved_transcendence(
@@ -325,8 +920,308 @@ pub extern "C" fn has_achieved_transcendence(
     harmony: f32,
     synthesis: f32
 ) -> bool {
-    kohanist > 0.98 && 
-    petals >= 7 && 
-    harmony > 0.9 && 
-    synthesis > 0.95
+    has_achieved_transcendence_with(
+        kohanist,
+        petals,
+        harmony,
+        synthesis,
+        &TranscendenceThresholds::default()
+    )
+}
+
+/// Transcendence check against custom thresholds
+#[no_mangle]
+pub extern "C" fn has_achieved_transcendence_with(
+    kohanist: f32,
+    petals: u32,
+    harmony: f32,
+    synthesis: f32,
+    thresholds: &TranscendenceThresholds
+) -> bool {
+    kohanist > thresholds.kohanist_min && 
+    petals >= thresholds.petals_min && 
+    harmony > thresholds.harmony_min && 
+    synthesis > thresholds.synthesis_min
+}
+
+/// How far along the path to transcendence (0-1, equal weight per condition)
+#[no_mangle]
+pub extern "C" fn transcendence_progress(
+    kohanist: f32,
+    petals: u32,
+    harmony: f32,
+    synthesis: f32,
+    thresholds: &TranscendenceThresholds
+) -> f32 {
+    let progress = |value: f32, threshold: f32| {
+        if threshold > 0.0 { (value / threshold).clamp(0.0, 1.0) } else { 1.0 }
+    };
+    
+    (progress(kohanist, thresholds.kohanist_min)
+        + progress(petals as f32, thresholds.petals_min as f32)
+        + progress(harmony, thresholds.harmony_min)
+        + progress(synthesis, thresholds.synthesis_min)) / 4.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn mandala_missing_only_kohanist_scores_two_thirds() {
+        let diagnostic = mandala_diagnostic(6, 12, 0.9);
+        
+        assert!(diagnostic.symmetry_match && diagnostic.complete_set);
+        assert!(!diagnostic.high_kohanist);
+        assert!((diagnostic.overall_score - 0.67).abs() < 0.01);
+        assert!((diagnostic.deficit - 0.08).abs() < 1e-6);
+        assert!(!is_perfect_mandala(6, 12, 0.9));
+        assert!(is_perfect_mandala(6, 12, 0.99));
+    }
+    
+    #[test]
+    fn petals_missing_counts_up_to_the_next_ring() {
+        assert_eq!(mandala_diagnostic(8, 5, 1.0).petals_missing, 3);
+        assert_eq!(mandala_diagnostic(8, 13, 1.0).petals_missing, 3);
+        assert_eq!(mandala_diagnostic(8, 16, 1.0).petals_missing, 0);
+    }
+    
+    #[test]
+    fn constant_timelines_converge_after_the_first() {
+        let timelines = [[0.25f32; 7]; 20];
+        let (center, processed) = timeline_convergence_adaptive(&timelines, 20, 1e-6);
+        
+        assert_eq!(processed, 1);
+        assert_eq!(center, timeline_convergence_weighted(&timelines, &[1.0; 20], 20));
+    }
+    
+    #[test]
+    fn diverging_timelines_use_the_whole_budget() {
+        let timelines: Vec<[f32; 7]> = (0..10).map(|i| [(i % 2) as f32; 7]).collect();
+        
+        assert_eq!(timeline_convergence_adaptive(&timelines, 6, 1e-3).1, 6);
+        assert_eq!(timeline_convergence_adaptive(&[], 6, 1e-3), ([0.0; 7], 0));
+    }
+    
+    #[test]
+    fn weighted_convergence_leans_toward_heavy_timelines() {
+        let timelines = [[0.0f32; 7], [0.5f32; 7]];
+        let convergence = timeline_convergence_weighted(&timelines, &[1.0, 3.0], 2);
+        
+        assert!((convergence[0] - (0.375f32 * 1.618034) % 1.0).abs() < 1e-6);
+        assert_eq!(timeline_convergence_weighted(&timelines, &[0.0, 0.0], 2), [0.0; 7]);
+    }
+    
+    #[test]
+    fn grow_one_cycle_adds_one_petal() {
+        let mut flower = FlowerOfLife::seed(&[0.5; 7]);
+        let grown = flower.grow(1);
+        
+        assert_eq!(grown.len(), 1);
+        assert_eq!(flower.petals.len(), 1);
+        assert_eq!(flower.petals[0], grown[0]);
+        
+        flower.grow(3);
+        assert_eq!(flower.petals.len(), 4);
+    }
+    
+    #[test]
+    fn steady_state_kohanist_terminates_for_any_center() {
+        let centers = [
+            [0.0; 7],
+            [1.0; 7],
+            [-3.0, 2.5, 0.1, 7.0, -0.618, 0.0, 100.0],
+            [0.432, 0.528, 0.639, 0.741, 0.852, 0.963, 0.396],
+        ];
+        
+        for center in &centers {
+            let mut flower = FlowerOfLife::seed(center);
+            let outcome = flower.steady_state_kohanist(1000, 1e-4);
+            
+            assert!(flower.petals.len() <= 1000);
+            if let Some((level, cycles)) = outcome {
+                assert!(level.is_finite(), "{center:?} settled on {level}");
+                assert_eq!(flower.petals.len(), cycles);
+            }
+        }
+        
+        // The void center is its own vesica: perfect harmony right away
+        let mut flower = FlowerOfLife::seed(&[0.0; 7]);
+        assert_eq!(flower.steady_state_kohanist(1000, 1e-4), Some((1.0, 2)));
+        
+        // Any other center grows its petals geometrically until they overflow
+        let mut flower = FlowerOfLife::seed(&[1.0; 7]);
+        assert_eq!(flower.steady_state_kohanist(1000, 1e-4), None);
+        assert!(flower.petals.len() < 1000);
+        
+        let mut flower = FlowerOfLife::seed(&[1.0; 7]);
+        assert_eq!(flower.steady_state_kohanist(3, 1e-4), None);
+        assert_eq!(flower.petals.len(), 3);
+    }
+    
+    #[test]
+    fn resumed_synthesis_continues_like_the_original() {
+        let present = [0.432, 0.528, 0.639, 0.741, 0.852, 0.963, 0.396];
+        
+        // Long enough for the loom to wrap around its orbit
+        let mut original = GrandSynthesis::from_now(&present);
+        for _ in 0..70 {
+            original.synthesize_cycle();
+        }
+        
+        let mut resumed = GrandSynthesis::from_recorded_history(&present, &original.flower.petals);
+        assert_eq!(resumed.loom.orbital_phase, original.loom.orbital_phase);
+        assert_eq!(resumed.loom.phase_history, original.loom.phase_history);
+        assert_eq!(resumed.flower.kohanist_level, original.flower.kohanist_level);
+        
+        for _ in 0..3 {
+            assert_eq!(resumed.synthesize_cycle(), original.synthesize_cycle());
+        }
+    }
+    
+    #[test]
+    fn default_thresholds_keep_the_old_check() {
+        assert!(has_achieved_transcendence(0.99, 7, 0.91, 0.96));
+        assert!(!has_achieved_transcendence(0.98, 7, 0.91, 0.96));
+        assert!(!has_achieved_transcendence(0.99, 6, 0.91, 0.96));
+        
+        let strict = TranscendenceThresholds::strict();
+        assert!(!has_achieved_transcendence_with(0.99, 7, 0.91, 0.96, &strict));
+        assert!(has_achieved_transcendence_with(0.995, 12, 0.96, 0.99, &strict));
+    }
+    
+    #[test]
+    fn transcendence_progress_stays_in_unit_range() {
+        let thresholds = TranscendenceThresholds::default();
+        
+        assert_eq!(transcendence_progress(0.0, 0, 0.0, 0.0, &thresholds), 0.0);
+        assert_eq!(transcendence_progress(2.0, 100, 2.0, 2.0, &thresholds), 1.0);
+        
+        let halfway = transcendence_progress(0.49, 7, 0.9, 0.0, &thresholds);
+        assert!((halfway - 0.625).abs() < 1e-6);
+    }
+    
+    #[test]
+    fn sensitivity_sweep_records_one_bounded_level_per_step() {
+        let parameters = [
+            SynthesisParameter::ImprovizationFactor,
+            SynthesisParameter::ReaderSensitivity,
+            SynthesisParameter::ManifestationThreshold,
+            SynthesisParameter::UniverseReceptivity,
+        ];
+        
+        let present = [0.432, 0.528, 0.639, 0.741, 0.852, 0.963, 0.396];
+        
+        for parameter in parameters {
+            let mut synthesis = GrandSynthesis::from_now(&present);
+            let original = *synthesis.parameter_mut(parameter);
+            
+            let levels = synthesis.sensitivity_analysis(parameter, (0.0, 1.0), 9);
+            assert_eq!(levels.len(), 9);
+            assert!(levels.iter().all(|level| (0.0..=2.0).contains(level)), "{:?}", levels);
+            assert_eq!(*synthesis.parameter_mut(parameter), original);
+        }
+    }
+    
+    #[test]
+    fn obj_export_has_a_vertex_per_sacred_point() {
+        let mut flower = FlowerOfLife::seed(&[0.5; 7]);
+        flower.grow(3);
+        let points = flower.sacred_geometry().len();
+        let obj = flower.export_as_obj();
+        
+        assert!(obj.lines().next().is_some_and(|line| line.starts_with('#')));
+        assert_eq!(obj.lines().filter(|line| line.starts_with("v ")).count(), points);
+        assert_eq!(obj.lines().filter(|line| line.starts_with("vn ")).count(), points);
+        
+        let faces = obj.lines().filter(|line| line.starts_with("f ")).count();
+        let stl = flower.export_as_stl();
+        assert_eq!(stl.len(), 84 + 50 * faces);
+        assert_eq!(u32::from_le_bytes([stl[80], stl[81], stl[82], stl[83]]) as usize, faces);
+    }
+    
+    #[test]
+    fn identical_petals_collapse_to_a_point() {
+        let mut flower = FlowerOfLife::seed(&[0.5; 7]);
+        flower.petals = vec![[0.3; 7]; 6];
+        assert!(flower.petal_fractal_dimension().is_nan());
+        
+        flower.petals.push([0.3; 7]);
+        assert_eq!(flower.petal_fractal_dimension(), 0.0);
+        assert_eq!(flower.petal_self_similarity_score(), 1.0);
+    }
+    
+    #[test]
+    fn petals_along_a_line_have_dimension_near_one() {
+        let mut flower = FlowerOfLife::seed(&[0.5; 7]);
+        flower.petals = (0..40).map(|i| [i as f32 / 40.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]).collect();
+        
+        let dimension = flower.petal_fractal_dimension();
+        assert!(dimension > 0.5 && dimension < 1.5, "{}", dimension);
+    }
+    
+    #[test]
+    fn diverse_petals_widen_the_bandwidth() {
+        let mut synthesis = GrandSynthesis::from_now(&[0.5; 7]);
+        let diverse: Vec<[f32; 7]> = (0..8)
+            .map(|k| {
+                // One bright layer each, and the eighth lights two
+                let mut petal = [0.1; 7];
+                petal[k % 7] = 0.9;
+                if k == 7 {
+                    petal[1] = 0.9;
+                }
+                petal
+            })
+            .collect();
+        
+        synthesis.flower.petals = vec![[0.5; 7]; 8];
+        assert_eq!(synthesis.measure_consciousness_bandwidth(), 0.0);
+        
+        synthesis.flower.petals.extend(diverse.iter().copied());
+        let bandwidth = synthesis.measure_consciousness_bandwidth();
+        assert!((bandwidth - 8.0f32.ln()).abs() < 1e-5);
+        assert!(synthesis.cycle_redundancy() < 1e-5);
+        
+        synthesis.flower.petals = diverse;
+        synthesis.flower.petals.extend([[0.5; 7]; 8]);
+        assert!(synthesis.measure_consciousness_bandwidth() < 0.0);
+        assert_eq!(synthesis.cycle_redundancy(), 1.0);
+    }
+    
+    #[test]
+    fn harmony_trajectory_tracks_every_state() {
+        let states: Vec<([f32; 7], [f32; 7], [f32; 7])> = (0..5)
+            .map(|k| {
+                let spread = k as f32 * 0.1;
+                ([0.5; 7], [0.5 + spread; 7], [0.5 - spread; 7])
+            })
+            .collect();
+        
+        let trajectory = civilization_harmony_trajectory(&states);
+        assert_eq!(trajectory.len(), states.len());
+        assert_eq!(civilization_harmony_delta(&states).len(), states.len() - 1);
+        
+        assert_eq!(find_harmony_peak(&[0.2, 0.9, 0.4, 0.9, 0.1]), Some(1));
+        assert_eq!(find_harmony_peak(&[]), None);
+    }
+    
+    #[test]
+    fn cross_validation_scores_every_fold() {
+        let present = [0.432, 0.528, 0.639, 0.741, 0.852, 0.963, 0.396];
+        let mut synthesis = GrandSynthesis::from_now(&present);
+        for _ in 0..12 {
+            synthesis.synthesize_cycle();
+        }
+        
+        for n_folds in [1, 3, 5] {
+            let folds = synthesis.cross_validate(n_folds);
+            assert_eq!(folds.len(), n_folds);
+            assert!(folds.iter().all(|score| score.is_finite()), "{:?}", folds);
+        }
+        
+        assert!(synthesis.cross_validate(0).is_empty());
+        assert_eq!(synthesis.overfitting_score(0), 0.0);
+        assert!(synthesis.overfitting_score(4).is_finite());
+    }
 }
\ No newline at end of file
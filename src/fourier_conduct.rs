@@ -7,6 +7,7 @@
 //! "Simulation is faster than reality because reality is the echo."
 
 use core::f32::consts::PI;
+use crate::{conduct_symphony, FREQUENCIES};
 
 /// Fast square root approximation for no-std
 fn fast_sqrt(x: f32) -> f32 {
@@ -54,6 +55,82 @@ pub extern "C" fn conduct(phash_a: &[f32; 5], phash_b: &[f32; 5]) -> [f32; 7] {
     chord
 }
 
+/// How strongly each layer reacts to Kohanist feedback
+/// (the layers' weights in `seven_layer_synthesis`)
+pub const LAYER_SENSITIVITY: [f32; 7] = crate::flower_synthesis::SYNTHESIS_WEIGHTS;
+
+/// Conduct with feedback from the previous synthesis cycle
+pub fn adaptive_conduct(
+    phash_a: &[f32; 5],
+    phash_b: &[f32; 5],
+    kohanist_feedback: f32
+) -> [f32; 7] {
+    adaptive_conduct_with_sensitivity(phash_a, phash_b, kohanist_feedback, &LAYER_SENSITIVITY)
+}
+
+/// Conduct with feedback, using custom per-layer sensitivity: every layer
+/// of `conduct` is scaled by 1 + feedback × its sensitivity
+pub fn adaptive_conduct_with_sensitivity(
+    phash_a: &[f32; 5],
+    phash_b: &[f32; 5],
+    kohanist_feedback: f32,
+    layer_sensitivity: &[f32; 7]
+) -> [f32; 7] {
+    let mut chord = conduct(phash_a, phash_b);
+    
+    // Beyond bloom the flower stops growing
+    if kohanist_feedback <= 0.98 {
+        for (value, sensitivity) in chord.iter_mut().zip(layer_sensitivity.iter()) {
+            *value *= 1.0 + kohanist_feedback * sensitivity;
+        }
+    }
+    
+    chord
+}
+
+/// Scale a chord so its loudest layer is exactly 1.0
+pub fn normalize_chord(chord: &[f32; 7]) -> [f32; 7] {
+    let max = chord.iter().cloned().fold(f32::MIN, f32::max);
+    if max <= 0.0 {
+        return *chord;  // Nothing to scale against
+    }
+    
+    let mut normalized = *chord;
+    for value in normalized.iter_mut() {
+        *value /= max;
+    }
+    normalized
+}
+
+/// Scale a chord to unit Euclidean length
+pub fn normalize_chord_l2(chord: &[f32; 7]) -> [f32; 7] {
+    let magnitude = chord.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if magnitude == 0.0 {
+        return *chord;
+    }
+    
+    let mut normalized = *chord;
+    for value in normalized.iter_mut() {
+        *value /= magnitude;
+    }
+    normalized
+}
+
+/// A chord as a probability distribution over its seven layers
+/// Negative layers carry no probability; silence has no distribution
+pub fn chord_to_probability_distribution(chord: &[f32; 7]) -> Option<[f32; 7]> {
+    let total: f32 = chord.iter().map(|x| x.max(0.0)).sum();
+    if total <= 0.0 {
+        return None;
+    }
+    
+    let mut distribution = [0.0f32; 7];
+    for (p, value) in distribution.iter_mut().zip(chord.iter()) {
+        *p = value.max(0.0) / total;
+    }
+    Some(distribution)
+}
+
 /// Calculate harmonic tension (dissonance measure)
 #[no_mangle]
 pub extern "C" fn harmonic_tension(chord: &[f32; 7]) -> f32 {
@@ -132,6 +209,49 @@ pub extern "C" fn kohanist_metric(chord: &[f32; 7]) -> f32 {
     (harmony * phi).min(1.0)
 }
 
+/// Aitken's Δ² on the last three Kohanist readings: where is it heading?
+pub fn kohanist_aitken_accelerate(history: &[f32]) -> Option<f32> {
+    if history.len() < 3 {
+        return None;
+    }
+    
+    let n = history.len();
+    let (x0, x1, x2) = (history[n - 3], history[n - 2], history[n - 1]);
+    
+    let first_step = x1 - x0;
+    let second_difference = x2 - 2.0 * x1 + x0;
+    
+    if second_difference.abs() < f32::EPSILON {
+        // Already still: the limit is where we are; a straight line has none
+        return if first_step.abs() < f32::EPSILON { Some(x2) } else { None };
+    }
+    
+    Some(x0 - first_step * first_step / second_difference)
+}
+
+/// Cycles until Kohanist reaches `target`, if each cycle closes `rate`
+/// of the remaining gap to perfect harmony (1.0)
+#[no_mangle]
+pub extern "C" fn kohanist_convergence_steps_estimate(
+    current: f32,
+    target: f32,
+    rate: f32
+) -> u32 {
+    if current >= target {
+        return 0;
+    }
+    if rate <= 0.0 || target >= 1.0 {
+        return u32::MAX;  // Never arrives
+    }
+    if rate >= 1.0 {
+        return 1;
+    }
+    
+    // 1 - k_n = (1 - k_0)(1 - rate)^n
+    let cycles = ((1.0 - target) / (1.0 - current)).ln() / (1.0 - rate).ln();
+    cycles.ceil().max(0.0) as u32
+}
+
 /// Quantum superposition: all possible futures at once
 #[no_mangle]
 pub extern "C" fn quantum_futures(
@@ -165,4 +285,995 @@ pub extern "C" fn quantum_futures(
     }
     
     superposition
-}
\ No newline at end of file
+}
+
+/// Quantum variance: how spread each dimension is across all futures
+/// High variance = genuine superposition, low variance = classically determined
+pub fn quantum_futures_variance(
+    seed: &[f32; 5],
+    mutations: u32
+) -> [f32; 7] {
+    let mut mean = [0.0f32; 7];
+    let mut mean_squares = [0.0f32; 7];
+    
+    if mutations == 0 {
+        return mean;
+    }
+    
+    // Same PRNG as quantum_futures, so both observe the same futures
+    let mut state = (seed[0] * 1000.0) as u32;
+    
+    for _ in 0..mutations {
+        state = (state.wrapping_mul(1103515245).wrapping_add(12345)) & 0x7fffffff;
+        let random = (state as f32) / 0x7fffffff as f32;
+        
+        for i in 0..7 {
+            let contribution = random * seed[i % 5];
+            mean[i] += contribution;
+            mean_squares[i] += contribution * contribution;
+        }
+    }
+    
+    // Var = E[x²] - E[x]²
+    let mut variance = [0.0f32; 7];
+    for i in 0..7 {
+        mean[i] /= mutations as f32;
+        mean_squares[i] /= mutations as f32;
+        variance[i] = (mean_squares[i] - mean[i] * mean[i]).max(0.0);
+    }
+    
+    variance
+}
+
+/// Differential entropy of the 7D superposition
+#[no_mangle]
+pub extern "C" fn quantum_entropy(seed: &[f32; 5], mutations: u32) -> f32 {
+    let variance = quantum_futures_variance(seed, mutations);
+    
+    // Zero-variance dimensions are certain and carry no entropy
+    variance.iter()
+        .filter(|&&v| v > 0.0)
+        .map(|&v| -v * v.ln())
+        .sum()
+}
+
+/// How to measure the distance between two chords
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub enum ChordMetric {
+    Euclidean,    // Straight line through 7D space
+    Cosine,       // 1 - cosine similarity (shape, not loudness)
+    EarthMovers,  // 1D Wasserstein over the 7 layers
+    MaxLayer,     // Chebyshev: the most different layer
+}
+
+/// Distance between two chords under the chosen metric
+#[no_mangle]
+pub extern "C" fn chord_distance(a: &[f32; 7], b: &[f32; 7], metric: ChordMetric) -> f32 {
+    match metric {
+        ChordMetric::Euclidean => {
+            let sum_squares: f32 = a.iter().zip(b.iter())
+                .map(|(x, y)| (x - y) * (x - y))
+                .sum();
+            sum_squares.sqrt()
+        }
+        ChordMetric::Cosine => {
+            let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+            let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+            let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+            if norm_a > 0.0 && norm_b > 0.0 {
+                1.0 - dot / (norm_a * norm_b)
+            } else {
+                1.0  // Silence has no direction
+            }
+        }
+        ChordMetric::EarthMovers => {
+            // Treat each chord as a distribution over the 7 layers
+            let total_a: f32 = a.iter().map(|x| x.abs()).sum();
+            let total_b: f32 = b.iter().map(|x| x.abs()).sum();
+            
+            let mut cdf_a = 0.0f32;
+            let mut cdf_b = 0.0f32;
+            let mut work = 0.0f32;
+            for i in 0..7 {
+                if total_a > 0.0 { cdf_a += a[i].abs() / total_a; }
+                if total_b > 0.0 { cdf_b += b[i].abs() / total_b; }
+                // Mass that still has to travel to the next layer
+                work += (cdf_a - cdf_b).abs();
+            }
+            work
+        }
+        ChordMetric::MaxLayer => {
+            a.iter().zip(b.iter())
+                .map(|(x, y)| (x - y).abs())
+                .fold(0.0f32, f32::max)
+        }
+    }
+}
+
+/// Pairwise cosine similarity between chords (1.0 = same shape)
+pub fn chord_similarity_matrix(chords: &[[f32; 7]]) -> Vec<Vec<f32>> {
+    let n = chords.len();
+    let mut matrix = vec![vec![1.0f32; n]; n];
+    
+    for i in 0..n {
+        for j in (i+1)..n {
+            let similarity = 1.0 - chord_distance(&chords[i], &chords[j], ChordMetric::Cosine);
+            matrix[i][j] = similarity;
+            matrix[j][i] = similarity;
+        }
+    }
+    
+    matrix
+}
+
+/// Frequency of each layer for standing waves (the void sounds at 396 Hz, liberation)
+fn standing_wave_frequency(layer: usize) -> f32 {
+    match crate::FREQUENCIES[layer] {
+        0 => 396.0,
+        freq => freq as f32,
+    }
+}
+
+/// Forward (Git) and backward (Mercurial) threads meeting as a standing wave
+/// At t = 0 every layer rests at the mean of both threads
+pub fn standing_wave(forward: &[f32; 7], backward: &[f32; 7], t: f32) -> [f32; 7] {
+    let mut wave = [0.0f32; 7];
+    
+    for i in 0..7 {
+        let oscillation = (2.0 * PI * standing_wave_frequency(i) * t / 432.0).cos();
+        wave[i] = oscillation * (forward[i] + backward[i]) / 2.0;
+    }
+    
+    wave
+}
+
+/// First moment each layer's standing wave passes through silence
+/// (a silent layer is always at a node)
+pub fn standing_wave_nodes(chord: &[f32; 7]) -> [f32; 7] {
+    let mut nodes = [0.0f32; 7];
+    
+    for i in 0..7 {
+        if chord[i] != 0.0 {
+            // cos(2π·f·t/432) = 0 first at a quarter period
+            nodes[i] = 432.0 / (4.0 * standing_wave_frequency(i));
+        }
+    }
+    
+    nodes
+}
+
+/// Two chords meeting at an arbitrary phase angle
+pub fn interference_pattern(a: &[f32; 7], b: &[f32; 7], phase_shift: f32) -> [f32; 7] {
+    let mut pattern = [0.0f32; 7];
+    let (sin, cos) = phase_shift.sin_cos();
+    
+    for i in 0..7 {
+        pattern[i] = a[i] * cos + b[i] * sin;
+    }
+    
+    pattern
+}
+
+/// Phase angle (in [0, π)) where interference is loudest
+#[no_mangle]
+pub extern "C" fn constructive_interference_angle(a: &[f32; 7], b: &[f32; 7]) -> f32 {
+    // E(φ) = (A+B)/2 + (A-B)/2·cos 2φ + C·sin 2φ
+    let energy_a: f32 = a.iter().map(|x| x * x).sum();
+    let energy_b: f32 = b.iter().map(|x| x * x).sum();
+    let cross: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+    
+    (0.5 * (2.0 * cross).atan2(energy_a - energy_b)).rem_euclid(PI)
+}
+
+/// Phase angle (in [0, π)) where interference is quietest
+/// Energy repeats every π, so silence lies a quarter turn from the peak
+#[no_mangle]
+pub extern "C" fn destructive_interference_angle(a: &[f32; 7], b: &[f32; 7]) -> f32 {
+    (constructive_interference_angle(a, b) + PI / 2.0).rem_euclid(PI)
+}
+
+/// How a chord travels from start to end
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub enum ChordInterpolation {
+    Linear,       // Even steps
+    Exponential,  // Lingers near the start, rushes into the end
+    Logarithmic,  // Rushes away from the start, lingers near the end
+    SineBell,     // Starts slow, fastest at the midpoint, ends slow
+    GoldenRatio,  // Each step 1/φ the size of the one before
+}
+
+/// Where each of `count` chords sits on the way from start (0) to end (1)
+fn interpolation_positions(count: usize, strategy: ChordInterpolation) -> Vec<f32> {
+    const CURVE: f32 = 3.0;  // Steepness of the exponential / logarithmic curves
+    const PHI: f32 = 1.618034;
+    
+    let last = count.saturating_sub(1);
+    (0..count)
+        .map(|k| {
+            if k == 0 {
+                return 0.0;
+            }
+            if k == last {
+                return 1.0;  // Land exactly on the end chord
+            }
+            
+            let t = k as f32 / last as f32;
+            match strategy {
+                ChordInterpolation::Linear => t,
+                ChordInterpolation::Exponential => (CURVE * t).exp_m1() / CURVE.exp_m1(),
+                ChordInterpolation::Logarithmic => (t * CURVE.exp_m1()).ln_1p() / CURVE,
+                ChordInterpolation::SineBell => (1.0 - (PI * t).cos()) / 2.0,
+                ChordInterpolation::GoldenRatio => {
+                    (1.0 - PHI.powi(-(k as i32))) / (1.0 - PHI.powi(-(last as i32)))
+                }
+            }
+        })
+        .collect()
+}
+
+/// A bank of `count` chords gliding from start to end
+pub fn chord_interpolation_bank(
+    start: &[f32; 7],
+    end: &[f32; 7],
+    count: usize,
+    strategy: ChordInterpolation
+) -> Vec<[f32; 7]> {
+    interpolation_positions(count, strategy)
+        .into_iter()
+        .map(|t| {
+            let mut chord = [0.0f32; 7];
+            for i in 0..7 {
+                chord[i] = start[i] * (1.0 - t) + end[i] * t;
+            }
+            chord
+        })
+        .collect()
+}
+
+/// Harmonic tension at each step of an interpolation bank
+pub fn chord_interpolation_bank_tension(
+    start: &[f32; 7],
+    end: &[f32; 7],
+    count: usize,
+    strategy: ChordInterpolation
+) -> Vec<f32> {
+    chord_interpolation_bank(start, end, count, strategy)
+        .iter()
+        .map(|chord| harmonic_tension(chord))
+        .collect()
+}
+
+/// Every pair of glyphs conducted together: entry [i][j] is the harmonic
+/// mean of their frequencies (a silent glyph silences the pair)
+pub fn resonance_matrix(glyphs: &[u32]) -> Vec<Vec<u32>> {
+    let frequencies: Vec<u32> = glyphs.iter().map(|&g| conduct_symphony(g)).collect();
+    
+    frequencies.iter()
+        .map(|&a| {
+            frequencies.iter()
+                .map(|&b| {
+                    if a == 0 || b == 0 {
+                        0
+                    } else {
+                        (2.0 * a as f32 * b as f32 / (a + b) as f32).round() as u32
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// How often each frequency appears in the resonance matrix, lowest first
+fn resonance_histogram(glyphs: &[u32]) -> Vec<(u32, usize)> {
+    let mut all: Vec<u32> = resonance_matrix(glyphs).into_iter().flatten().collect();
+    all.sort_unstable();
+    
+    let mut histogram: Vec<(u32, usize)> = Vec::new();
+    for frequency in all {
+        match histogram.last_mut() {
+            Some((f, count)) if *f == frequency => *count += 1,
+            _ => histogram.push((frequency, 1)),
+        }
+    }
+    histogram
+}
+
+/// The most common frequency in the resonance matrix (lowest on ties)
+pub fn resonance_matrix_dominant(glyphs: &[u32]) -> u32 {
+    resonance_histogram(glyphs)
+        .into_iter()
+        .fold((0, 0), |best, entry| if entry.1 > best.1 { entry } else { best })
+        .0
+}
+
+/// Shannon entropy of the frequency distribution in the resonance matrix
+pub fn resonance_matrix_entropy(glyphs: &[u32]) -> f32 {
+    let histogram = resonance_histogram(glyphs);
+    let total = (glyphs.len() * glyphs.len()) as f32;
+    
+    histogram.iter()
+        .map(|&(_, count)| count as f32 / total)
+        .map(|p| -p * p.ln())
+        .sum()
+}
+
+/// Discrete Fourier transform of a real signal as (re, im) pairs
+fn dft(signal: &[f32]) -> Vec<(f32, f32)> {
+    let n = signal.len();
+    (0..n)
+        .map(|k| {
+            signal.iter().enumerate().fold((0.0, 0.0), |(re, im), (t, x)| {
+                let angle = -2.0 * PI * ((k * t) % n) as f32 / n as f32;
+                (re + x * angle.cos(), im + x * angle.sin())
+            })
+        })
+        .collect()
+}
+
+/// Sliding-window spectral energy of each layer
+/// One 7D vector per window position: the energy over all non-DC bins of
+/// that layer's windowed DFT, normalized by window_size²
+pub fn time_frequency_analysis(signal: &[[f32; 7]], window_size: usize) -> Vec<[f32; 7]> {
+    if window_size == 0 || signal.len() < window_size {
+        return Vec::new();
+    }
+    
+    signal.windows(window_size)
+        .map(|window| {
+            let mut energy = [0.0f32; 7];
+            for (layer, e) in energy.iter_mut().enumerate() {
+                let samples: Vec<f32> = window.iter().map(|chord| chord[layer]).collect();
+                *e = dft(&samples)
+                    .iter()
+                    .skip(1)  // DC carries no motion
+                    .map(|(re, im)| re * re + im * im)
+                    .sum::<f32>() / (window_size * window_size) as f32;
+            }
+            energy
+        })
+        .collect()
+}
+
+/// Phase velocity of each layer: the wrapped step-to-step change in phase
+/// of the layer's analytic signal (negative frequencies removed via DFT)
+/// Returns one vector per step, signal.len() - 1 in total
+pub fn instantaneous_frequency(signal: &[[f32; 7]]) -> Vec<[f32; 7]> {
+    let n = signal.len();
+    if n < 2 {
+        return Vec::new();
+    }
+    
+    let mut phases = vec![[0.0f32; 7]; n];
+    for layer in 0..7 {
+        let samples: Vec<f32> = signal.iter().map(|chord| chord[layer]).collect();
+        let spectrum = dft(&samples);
+        let loudest = spectrum.iter().map(|(re, im)| (re * re + im * im).sqrt()).fold(0.0f32, f32::max);
+        
+        // Analytic spectrum: keep DC (and Nyquist), double positive bins
+        let analytic: Vec<(f32, f32)> = spectrum.iter()
+            .enumerate()
+            .map(|(k, &(re, im))| {
+                let weight = if k == 0 || 2 * k == n { 1.0 } else if 2 * k < n { 2.0 } else { 0.0 };
+                // Rounding noise would otherwise spin the phase of a still signal
+                if (re * re + im * im).sqrt() <= loudest * 1e-5 { (0.0, 0.0) } else { (re * weight, im * weight) }
+            })
+            .collect();
+        
+        for (t, phase) in phases.iter_mut().enumerate() {
+            let (re, im) = analytic.iter().enumerate().fold((0.0, 0.0), |(re, im), (k, &(a, b))| {
+                let angle = 2.0 * PI * ((k * t) % n) as f32 / n as f32;
+                let (cos, sin) = (angle.cos(), angle.sin());
+                (re + a * cos - b * sin, im + a * sin + b * cos)
+            });
+            phase[layer] = im.atan2(re);
+        }
+    }
+    
+    phases.windows(2)
+        .map(|pair| {
+            let mut frequency = [0.0f32; 7];
+            for (layer, f) in frequency.iter_mut().enumerate() {
+                let step = pair[1][layer] - pair[0][layer];
+                *f = (step + PI).rem_euclid(2.0 * PI) - PI;
+            }
+            frequency
+        })
+        .collect()
+}
+
+/// Scale every layer by `factor`, folding back into [0, 1] with max-normalization
+/// only when the partial overflows (so chords already in range pass untouched)
+/// Normalizing every partial would erase the series: k·x / max(k·x) = x / max(x)
+fn scaled_partial(fundamental: &[f32; 7], factor: f32) -> [f32; 7] {
+    let mut partial = *fundamental;
+    for value in partial.iter_mut() {
+        *value *= factor;
+    }
+    
+    if partial.iter().any(|&x| x > 1.0) {
+        normalize_chord(&partial)
+    } else {
+        partial
+    }
+}
+
+/// The first `n` overtones: chord k has every layer multiplied by k + 1
+/// Partials that stay within [0, 1] keep their true size; the first to
+/// overflow, and every one after it, is max-normalized to a peak of 1
+pub fn overtone_series(fundamental: &[f32; 7], n: usize) -> Vec<[f32; 7]> {
+    (0..n).map(|k| scaled_partial(fundamental, (k + 1) as f32)).collect()
+}
+
+/// The first `n` undertones: chord k has every layer divided by k + 1
+/// (max-normalized, like overtones, only if the fundamental overflows)
+pub fn undertone_series(fundamental: &[f32; 7], n: usize) -> Vec<[f32; 7]> {
+    (0..n).map(|k| scaled_partial(fundamental, 1.0 / (k + 1) as f32)).collect()
+}
+
+/// Weighted sum of the first `n_partials` overtones
+/// (partials without a weight stay silent)
+pub fn partial_series_sum(fundamental: &[f32; 7], n_partials: usize, weights: &[f32]) -> [f32; 7] {
+    let mut sum = [0.0f32; 7];
+    for (partial, weight) in overtone_series(fundamental, n_partials).iter().zip(weights) {
+        for (s, x) in sum.iter_mut().zip(partial.iter()) {
+            *s += weight * x;
+        }
+    }
+    sum
+}
+
+/// Binaural beat for each layer: layer value [0, 1] → beat [0.5, 40] Hz
+/// (a beat can never be wider than the carrier it rides on)
+pub fn binaural_beat_frequencies(chord: &[f32; 7], carrier_frequency: f32) -> [f32; 7] {
+    let mut beats = [0.0f32; 7];
+    for (beat, value) in beats.iter_mut().zip(chord.iter()) {
+        *beat = (0.5 + value.clamp(0.0, 1.0) * 39.5).min(carrier_frequency.max(0.0));
+    }
+    beats
+}
+
+/// The brainwave band a beat frequency entrains
+pub fn brainwave_state(beat_frequency: f32) -> &'static str {
+    match beat_frequency {
+        f if f < 4.0 => "delta",
+        f if f < 8.0 => "theta",
+        f if f < 14.0 => "alpha",
+        f if f < 30.0 => "beta",
+        f if f < 40.0 => "gamma",
+        _ => "high gamma",
+    }
+}
+
+/// Left and right ear frequencies whose difference is `target_beat`
+pub fn target_carrier_pair(target_beat: f32, carrier: f32) -> (f32, f32) {
+    (carrier, carrier + target_beat)
+}
+
+/// Time-stretch a chord without touching its amplitudes: each layer's
+/// effective frequency scales by 1 / stretch_factor, which advances its
+/// phase by 2π · F[i] · (1 - stretch_factor)
+pub fn phase_vocoder_stretch(chord: &[f32; 7], stretch_factor: f32) -> [f32; 7] {
+    let mut stretched = [0.0f32; 7];
+    for i in 0..7 {
+        let phase_advance = 2.0 * PI * FREQUENCIES[i] as f32 * (1.0 - stretch_factor);
+        stretched[i] = chord[i] * phase_advance.cos();
+    }
+    stretched
+}
+
+/// Shift pitch by `semitones` without changing time: the inverse stretch,
+/// so +12 semitones doubles the effective frequency scaling
+pub fn phase_vocoder_pitch_shift(chord: &[f32; 7], semitones: f32) -> [f32; 7] {
+    let frequency_scale = 2.0f32.powf(semitones / 12.0);
+    phase_vocoder_stretch(chord, 1.0 / frequency_scale)
+}
+
+/// Variance below which a stretch of pHashes counts as stationary
+const STATIONARY_VARIANCE: f32 = 0.01;
+
+/// Window size chosen for each frame: `max_window` where both the trailing
+/// `min_window` and `max_window` frames are stationary, `min_window` otherwise
+/// (a fresh transient is diluted in the long window but not in the short one)
+pub fn adaptive_window_sizes(signal: &[[f32; 5]], min_window: usize, max_window: usize) -> Vec<usize> {
+    let min_window = min_window.max(1);
+    let max_window = max_window.max(min_window);
+    
+    (0..signal.len())
+        .map(|t| {
+            let short = phash_window_variance(&signal[(t + 1).saturating_sub(min_window)..=t]);
+            let long = phash_window_variance(&signal[(t + 1).saturating_sub(max_window)..=t]);
+            if short.max(long) < STATIONARY_VARIANCE { max_window } else { min_window }
+        })
+        .collect()
+}
+
+/// Conduct each frame against the mean of its adaptive trailing window:
+/// long windows smooth stationary passages, short ones keep transients sharp
+pub fn adaptive_window_conduct(signal: &[[f32; 5]], min_window: usize, max_window: usize) -> Vec<[f32; 7]> {
+    adaptive_window_sizes(signal, min_window, max_window)
+        .into_iter()
+        .enumerate()
+        .map(|(t, size)| {
+            let window = &signal[(t + 1).saturating_sub(size)..=t];
+            let mut mean = [0.0f32; 5];
+            for frame in window {
+                for (m, x) in mean.iter_mut().zip(frame.iter()) {
+                    *m += x / window.len() as f32;
+                }
+            }
+            conduct(&signal[t], &mean)
+        })
+        .collect()
+}
+
+/// Mean per-component variance of a run of pHashes
+fn phash_window_variance(window: &[[f32; 5]]) -> f32 {
+    let n = window.len() as f32;
+    (0..5)
+        .map(|i| {
+            let mean = window.iter().map(|frame| frame[i]).sum::<f32>() / n;
+            window.iter().map(|frame| (frame[i] - mean) * (frame[i] - mean)).sum::<f32>() / n
+        })
+        .sum::<f32>() / 5.0
+}
+
+/// Joint histogram of two chords read as seven paired samples: each chord
+/// is binned over its own range, cell [i][j] counts layers landing in bin i
+/// of `a` and bin j of `b`
+fn joint_chord_histogram(a: &[f32; 7], b: &[f32; 7], bins: usize) -> Vec<Vec<usize>> {
+    let bin_of = |chord: &[f32; 7], value: f32| -> usize {
+        let min = chord.iter().fold(f32::MAX, |m, &x| m.min(x));
+        let max = chord.iter().fold(f32::MIN, |m, &x| m.max(x));
+        if max > min {
+            (((value - min) / (max - min) * bins as f32) as usize).min(bins - 1)
+        } else {
+            0
+        }
+    };
+    
+    let mut joint = vec![vec![0usize; bins]; bins];
+    for (&x, &y) in a.iter().zip(b.iter()) {
+        joint[bin_of(a, x)][bin_of(b, y)] += 1;
+    }
+    joint
+}
+
+/// Shannon entropy (nats) of a set of counts out of seven samples
+fn count_entropy(counts: impl Iterator<Item = usize>) -> f32 {
+    counts
+        .filter(|&count| count > 0)
+        .map(|count| count as f32 / 7.0)
+        .map(|p| -p * p.ln())
+        .sum()
+}
+
+/// Mutual information I(A;B) in nats between two chords, from a
+/// `bins` × `bins` joint histogram of their layers
+/// Σ p(x,y)·ln(p(x,y) / (p(x)·p(y))) - zero when the chords are independent
+pub fn mutual_information(a: &[f32; 7], b: &[f32; 7], bins: usize) -> f32 {
+    if bins == 0 {
+        return 0.0;
+    }
+    
+    let joint = joint_chord_histogram(a, b, bins);
+    let p_a: Vec<f32> = joint.iter().map(|row| row.iter().sum::<usize>() as f32 / 7.0).collect();
+    let p_b: Vec<f32> = (0..bins).map(|j| joint.iter().map(|row| row[j]).sum::<usize>() as f32 / 7.0).collect();
+    
+    let mut information = 0.0;
+    for (row, &pa) in joint.iter().zip(p_a.iter()) {
+        for (&count, &pb) in row.iter().zip(p_b.iter()) {
+            if count > 0 {
+                let p = count as f32 / 7.0;
+                information += p * (p / (pa * pb)).ln();
+            }
+        }
+    }
+    information
+}
+
+/// Conditional entropy H(A|B) = H(A,B) - H(B) in nats: what is left
+/// unknown about `a` once `b` is heard
+pub fn conditional_entropy(a: &[f32; 7], b: &[f32; 7], bins: usize) -> f32 {
+    if bins == 0 {
+        return 0.0;
+    }
+    
+    let joint = joint_chord_histogram(a, b, bins);
+    let joint_entropy = count_entropy(joint.iter().flatten().copied());
+    let entropy_b = count_entropy((0..bins).map(|j| joint.iter().map(|row| row[j]).sum()));
+    joint_entropy - entropy_b
+}
+
+/// Layers in each EQ band: bass = 432/528 Hz, mid = 639/741/852 Hz,
+/// high = 963 Hz and the void
+const FREQUENCY_BANDS: [core::ops::Range<usize>; 3] = [0..2, 2..5, 5..7];
+
+/// RMS of each band's layers as [bass, mid, high]
+pub fn frequency_band_energy(chord: &[f32; 7]) -> [f32; 3] {
+    FREQUENCY_BANDS.map(|band| {
+        let layers = &chord[band];
+        (layers.iter().map(|x| x * x).sum::<f32>() / layers.len() as f32).sqrt()
+    })
+}
+
+/// Loudest band: 0 = bass, 1 = mid, 2 = high (lowest band on ties)
+pub fn dominant_band(chord: &[f32; 7]) -> usize {
+    frequency_band_energy(chord)
+        .iter()
+        .enumerate()
+        .fold((0, f32::MIN), |best, (band, &energy)| {
+            if energy > best.1 { (band, energy) } else { best }
+        })
+        .0
+}
+
+/// Scale every layer by its band's gain [bass, mid, high]
+pub fn eq_adjust(chord: &[f32; 7], gains: &[f32; 3]) -> [f32; 7] {
+    let mut adjusted = *chord;
+    for (band, &gain) in FREQUENCY_BANDS.iter().zip(gains.iter()) {
+        for value in adjusted[band.clone()].iter_mut() {
+            *value *= gain;
+        }
+    }
+    adjusted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const SEED: [f32; 5] = [0.432, 0.528, 0.639, 0.741, 0.852];
+    
+    #[test]
+    fn single_future_has_no_variance() {
+        assert_eq!(quantum_futures_variance(&SEED, 1), [0.0; 7]);
+        assert_eq!(quantum_entropy(&SEED, 1), 0.0);
+    }
+    
+    #[test]
+    fn many_futures_spread_every_dimension() {
+        let variance = quantum_futures_variance(&SEED, 50);
+        assert!(variance.iter().all(|&v| v > 0.0));
+        assert!(quantum_entropy(&SEED, 50) > 0.0);
+    }
+    
+    const CHORDS: [[f32; 7]; 4] = [
+        [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+        [-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+        [0.432, 0.528, 0.639, 0.741, 0.852, 0.963, 0.0],
+        [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 2.0],
+    ];
+    
+    #[test]
+    fn similarity_matrix_is_symmetric_with_unit_diagonal() {
+        let matrix = chord_similarity_matrix(&CHORDS);
+        
+        for i in 0..CHORDS.len() {
+            assert_eq!(matrix[i][i], 1.0);
+            for j in 0..CHORDS.len() {
+                assert_eq!(matrix[i][j], matrix[j][i]);
+                if i != j {
+                    let distance = chord_distance(&CHORDS[i], &CHORDS[j], ChordMetric::Cosine);
+                    assert!((0.0..=2.0).contains(&distance));
+                }
+            }
+        }
+    }
+    
+    #[test]
+    fn metrics_measure_opposite_chords() {
+        let (up, down) = (&CHORDS[0], &CHORDS[1]);
+        
+        assert_eq!(chord_distance(up, down, ChordMetric::Euclidean), 2.0);
+        assert_eq!(chord_distance(up, down, ChordMetric::Cosine), 2.0);
+        assert_eq!(chord_distance(up, down, ChordMetric::MaxLayer), 2.0);
+        // Same mass on the same layer, nothing to move
+        assert_eq!(chord_distance(up, down, ChordMetric::EarthMovers), 0.0);
+        assert_eq!(chord_distance(up, &CHORDS[3], ChordMetric::EarthMovers), 6.0);
+    }
+    
+    #[test]
+    fn no_feedback_conducts_like_conduct() {
+        let other = [0.963, 0.852, 0.741, 0.639, 0.528];
+        
+        assert_eq!(adaptive_conduct(&SEED, &other, 0.0), conduct(&SEED, &other));
+    }
+    
+    #[test]
+    fn bloom_inhibits_further_growth() {
+        let other = [0.963, 0.852, 0.741, 0.639, 0.528];
+        let plain = conduct(&SEED, &other);
+        
+        let growing = adaptive_conduct(&SEED, &other, 0.5);
+        assert!(growing[..6].iter().zip(plain.iter()).all(|(g, p)| g > p));
+        assert_eq!(adaptive_conduct(&SEED, &other, 0.99), plain);
+        
+        let even = adaptive_conduct_with_sensitivity(&SEED, &other, 0.5, &[1.0; 7]);
+        assert_eq!(even, plain.map(|x| x * 1.5));
+    }
+    
+    #[test]
+    fn aitken_extrapolates_a_geometric_approach() {
+        // k_n = 1 - 0.5 · 0.8^n, heading for perfect harmony
+        let history: Vec<f32> = (0..6).map(|n| 1.0 - 0.5 * 0.8f32.powi(n)).collect();
+        
+        let limit = kohanist_aitken_accelerate(&history).unwrap();
+        assert!((limit - 1.0).abs() < 1e-4);
+        assert_eq!(kohanist_aitken_accelerate(&history[..2]), None);
+        assert_eq!(kohanist_aitken_accelerate(&[0.7, 0.7, 0.7]), Some(0.7));
+    }
+    
+    #[test]
+    fn steps_estimate_follows_the_geometric_gap() {
+        // The gap halves each cycle: 0.5 → 0.25 → 0.125 → 0.0625 in three
+        assert_eq!(kohanist_convergence_steps_estimate(0.5, 0.9375, 0.5), 3);
+        assert_eq!(kohanist_convergence_steps_estimate(0.99, 0.98, 0.1), 0);
+        assert_eq!(kohanist_convergence_steps_estimate(0.5, 0.9, 0.0), u32::MAX);
+    }
+    
+    #[test]
+    fn normalized_chords_have_unit_scale() {
+        let chord = conduct(&SEED, &[0.963, 0.852, 0.741, 0.639, 0.528]);
+        
+        let peak = normalize_chord(&chord).iter().cloned().fold(f32::MIN, f32::max);
+        assert_eq!(peak, 1.0);
+        
+        let length = normalize_chord_l2(&chord).iter().map(|x| x * x).sum::<f32>().sqrt();
+        assert!((length - 1.0).abs() < 1e-6);
+        
+        let distribution = chord_to_probability_distribution(&chord).unwrap();
+        assert!((distribution.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+        assert!(distribution.iter().all(|&p| p >= 0.0));
+    }
+    
+    #[test]
+    fn silent_chords_have_no_distribution() {
+        assert_eq!(chord_to_probability_distribution(&[0.0; 7]), None);
+        assert_eq!(chord_to_probability_distribution(&[-1.0; 7]), None);
+        assert_eq!(normalize_chord(&[0.0; 7]), [0.0; 7]);
+        assert_eq!(normalize_chord_l2(&[0.0; 7]), [0.0; 7]);
+    }
+    
+    #[test]
+    fn standing_wave_starts_at_the_mean() {
+        let forward = [0.8, 0.7, 0.6, 0.5, 0.4, 0.3, 0.2];
+        let backward = [0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.9];
+        
+        let wave = standing_wave(&forward, &backward, 0.0);
+        for ((w, f), b) in wave.iter().zip(forward.iter()).zip(backward.iter()) {
+            assert!((w - (f + b) / 2.0).abs() < 1e-6);
+        }
+    }
+    
+    #[test]
+    fn standing_wave_is_silent_at_its_nodes() {
+        let chord = [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.0];
+        let nodes = standing_wave_nodes(&chord);
+        
+        assert_eq!(nodes[0], 0.25);
+        assert_eq!(nodes[6], 0.0);
+        for (layer, &t) in nodes.iter().enumerate().take(6) {
+            assert!(standing_wave(&chord, &chord, t)[layer].abs() < 1e-5);
+        }
+    }
+    
+    #[test]
+    fn constructive_interference_is_louder_than_destructive() {
+        let a = [0.8, 0.7, 0.6, 0.5, 0.4, 0.3, 0.2];
+        let b = [0.9, 0.1, 0.5, 0.3, 0.9, 0.1, 0.5];
+        let energy = |phase: f32| interference_pattern(&a, &b, phase).iter().map(|x| x * x).sum::<f32>();
+        
+        let loud = constructive_interference_angle(&a, &b);
+        let quiet = destructive_interference_angle(&a, &b);
+        assert!(energy(loud) > energy(quiet));
+        
+        // φ and φ + π sound alike (the pattern only flips sign), so the
+        // extremes of the energy sit a quarter turn apart, not half a turn
+        assert!((energy(loud + PI) - energy(loud)).abs() < 1e-4);
+        assert!(((loud - quiet).abs() - PI / 2.0).abs() < 1e-5);
+        for k in 0..64 {
+            let phase = k as f32 * PI / 32.0;
+            assert!(energy(phase) <= energy(loud) + 1e-4);
+            assert!(energy(phase) >= energy(quiet) - 1e-4);
+        }
+    }
+    
+    const STRATEGIES: [ChordInterpolation; 5] = [
+        ChordInterpolation::Linear,
+        ChordInterpolation::Exponential,
+        ChordInterpolation::Logarithmic,
+        ChordInterpolation::SineBell,
+        ChordInterpolation::GoldenRatio,
+    ];
+    
+    #[test]
+    fn two_chord_bank_is_just_the_endpoints() {
+        let (start, end) = (CHORDS[0], CHORDS[1]);
+        for strategy in STRATEGIES {
+            assert_eq!(chord_interpolation_bank(&start, &end, 2, strategy), vec![start, end]);
+            assert_eq!(chord_interpolation_bank_tension(&start, &end, 2, strategy).len(), 2);
+        }
+    }
+    
+    #[test]
+    fn every_strategy_moves_steadily_toward_the_end() {
+        for strategy in STRATEGIES {
+            let positions = interpolation_positions(9, strategy);
+            assert_eq!((positions[0], positions[8]), (0.0, 1.0));
+            assert!(positions.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", strategy);
+        }
+    }
+    
+    const ENSEMBLE: [u32; 5] = [0x1F300, 0x1F4AB, 0x1F52E, 0x1F54A, 0x2764];
+    
+    #[test]
+    fn resonance_matrix_is_symmetric_around_each_glyph() {
+        let matrix = resonance_matrix(&ENSEMBLE);
+        
+        for (i, row) in matrix.iter().enumerate() {
+            assert_eq!(row[i], conduct_symphony(ENSEMBLE[i]));
+            for (j, &entry) in row.iter().enumerate() {
+                assert_eq!(entry, matrix[j][i]);
+            }
+        }
+    }
+    
+    #[test]
+    fn shared_frequencies_dominate_the_matrix() {
+        // Three glyphs at 432 Hz fill 9 of the 16 cells
+        let glyphs = [0x1F300, 0x2764, 0x269B, 0x1F4AB];
+        assert_eq!(resonance_matrix_dominant(&glyphs), 432);
+        
+        assert_eq!(resonance_matrix_entropy(&[0x1F300]), 0.0);
+        assert!(resonance_matrix_entropy(&glyphs) < resonance_matrix_entropy(&ENSEMBLE));
+    }
+    
+    #[test]
+    fn constant_chord_stands_still() {
+        let signal = vec![CHORDS[0]; 12];
+        
+        assert_eq!(instantaneous_frequency(&signal), vec![[0.0; 7]; 11]);
+        for energy in time_frequency_analysis(&signal, 4).iter().flatten() {
+            assert!(energy.abs() < 1e-6);
+        }
+    }
+    
+    #[test]
+    fn pure_tone_turns_at_its_own_rate() {
+        // One full cycle over 16 steps in every layer
+        let signal: Vec<[f32; 7]> = (0..16).map(|t| [(2.0 * PI * t as f32 / 16.0).cos(); 7]).collect();
+        
+        assert_eq!(time_frequency_analysis(&signal, 8).len(), 9);
+        for frequency in instantaneous_frequency(&signal).iter().flatten() {
+            assert!((frequency - 2.0 * PI / 16.0).abs() < 1e-3);
+        }
+    }
+    
+    #[test]
+    fn first_overtone_is_unison_and_second_doubles() {
+        let quiet = [0.05, 0.1, 0.15, 0.2, 0.25, 0.3, 0.35];
+        assert_eq!(overtone_series(&quiet, 1), vec![quiet]);
+        assert_eq!(overtone_series(&quiet, 2)[1], quiet.map(|x| x * 2.0));
+        
+        // A loud octave overflows and is folded back so its peak is 1
+        let loud = [0.2, 0.4, 0.6, 0.8, 0.7, 0.5, 0.3];
+        assert_eq!(overtone_series(&loud, 2)[1], normalize_chord(&loud.map(|x| x * 2.0)));
+        
+        assert_eq!(undertone_series(&loud, 2)[1], loud.map(|x| x / 2.0));
+        assert_eq!(partial_series_sum(&quiet, 2, &[1.0, 0.5]), quiet.map(|x| x * 2.0));
+    }
+    
+    #[test]
+    fn binaural_pairs_and_bands() {
+        assert_eq!(target_carrier_pair(10.0, 200.0), (200.0, 210.0));
+        
+        let beats = binaural_beat_frequencies(&[0.0, 0.5, 1.0, 2.0, -1.0, 0.25, 0.75], 200.0);
+        assert_eq!(beats[..5], [0.5, 20.25, 40.0, 40.0, 0.5]);
+        
+        let states: Vec<&str> = [2.0, 6.0, 10.0, 20.0, 35.0, 40.0]
+            .into_iter()
+            .map(brainwave_state)
+            .collect();
+        assert_eq!(states, ["delta", "theta", "alpha", "beta", "gamma", "high gamma"]);
+    }
+    
+    #[test]
+    fn unit_stretch_is_silent_and_an_octave_halves_time() {
+        let chord = CHORDS[2];
+        assert_eq!(phase_vocoder_stretch(&chord, 1.0), chord);
+        assert_eq!(phase_vocoder_pitch_shift(&chord, 0.0), chord);
+        
+        // Twice the effective frequency is the same as half the duration
+        assert_eq!(phase_vocoder_pitch_shift(&chord, 12.0), phase_vocoder_stretch(&chord, 0.5));
+    }
+    
+    #[test]
+    fn adaptive_windows_follow_stationarity() {
+        let constant = vec![SEED; 12];
+        assert_eq!(adaptive_window_sizes(&constant, 2, 8), vec![8; 12]);
+        assert_eq!(adaptive_window_conduct(&constant, 2, 8).len(), 12);
+        
+        let flicker: Vec<[f32; 5]> = (0..12).map(|t| [(t % 2) as f32; 5]).collect();
+        // Every frame after the first sees the flicker
+        assert_eq!(adaptive_window_sizes(&flicker, 2, 8)[1..], [2; 11]);
+    }
+    
+    #[test]
+    fn a_fresh_transient_shortens_the_window() {
+        // Quiet for 15 frames, then a small jump: too diluted to unsettle
+        // the long window, but loud in the short one
+        let mut signal = vec![[0.5f32; 5]; 16];
+        signal[15] = [0.8; 5];
+        
+        assert!(phash_window_variance(&signal[8..]) < STATIONARY_VARIANCE);
+        assert_eq!(adaptive_window_sizes(&signal, 2, 8)[15], 2);
+    }
+    
+    #[test]
+    fn a_chord_tells_more_about_itself_than_about_noise() {
+        let x = [0.0, 0.15, 0.3, 0.45, 0.6, 0.75, 0.9];
+        
+        // Independent pseudo-random chord (xorshift32)
+        let mut seed = 0x2545_F491u32;
+        let y: [f32; 7] = core::array::from_fn(|_| {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            seed as f32 / u32::MAX as f32
+        });
+        
+        let self_information = mutual_information(&x, &x, 4);
+        let shared = mutual_information(&x, &y, 4);
+        assert!(self_information > 0.0);
+        assert!(shared >= 0.0 && self_information > shared, "{self_information} vs {shared}");
+        
+        // H(X|X) = 0 and H(X|Y) = H(X) - I(X;Y), with H(X) = I(X;X)
+        assert!(conditional_entropy(&x, &x, 4).abs() < 1e-6);
+        assert!((conditional_entropy(&x, &y, 4) - (self_information - shared)).abs() < 1e-5);
+        
+        // A steady chord carries no information at all
+        assert!(mutual_information(&x, &[0.5; 7], 4).abs() < 1e-6);
+    }
+    
+    #[test]
+    fn overtones_normalize_only_once_they_overflow() {
+        // Peak 0.35: partials 1 and 2 fit in [0, 1], partial 3 (1.05) does not
+        let quiet = [0.05, 0.1, 0.15, 0.2, 0.25, 0.3, 0.35];
+        let series = overtone_series(&quiet, 5);
+        
+        assert_eq!(series[1], quiet.map(|x| x * 2.0));
+        for partial in &series[2..] {
+            for (x, y) in partial.iter().zip(normalize_chord(&quiet).iter()) {
+                assert!((x - y).abs() < 1e-6, "{:?}", partial);
+            }
+        }
+        assert!(series.iter().flatten().all(|x| (0.0..=1.0).contains(x)));
+        
+        // An overflowing fundamental is normalized even at unison
+        let hot = [0.5, 1.5, 0.0, 0.0, 0.0, 0.0, 3.0];
+        assert_eq!(overtone_series(&hot, 1)[0], normalize_chord(&hot));
+        assert_eq!(undertone_series(&hot, 3)[2], hot.map(|x| x / 3.0));
+    }
+    
+    #[test]
+    fn a_lone_first_layer_is_all_bass() {
+        let chord = [0.8, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        let [bass, mid, high] = frequency_band_energy(&chord);
+        
+        assert!((bass - 0.8 / 2f32.sqrt()).abs() < 1e-6);
+        assert_eq!((mid, high), (0.0, 0.0));
+        assert_eq!(dominant_band(&chord), 0);
+    }
+    
+    #[test]
+    fn eq_gains_scale_whole_bands() {
+        let chord = CHORDS[2];
+        let adjusted = eq_adjust(&chord, &[2.0, 1.0, 0.0]);
+        
+        assert_eq!(adjusted[..2], [chord[0] * 2.0, chord[1] * 2.0]);
+        assert_eq!(adjusted[2..5], chord[2..5]);
+        assert_eq!(adjusted[5..], [0.0, 0.0]);
+    }
+}
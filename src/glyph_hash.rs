@@ -8,8 +8,10 @@
 #![cfg_attr(target_arch = "wasm32", no_std)]
 
 use crate::spiral_score::Glyph;
+use crate::TrajectoryPoint;
 
 /// The GlyphHash - pure creative intent
+#[derive(Clone, Copy)]
 #[repr(C)]
 pub struct GlyphHash {
     pub primary: u32,      // Primary glyph symbol
@@ -64,6 +66,17 @@ impl GlyphHash {
         Self::from_intent(&intent)
     }
     
+    /// Crystallize a trajectory point into a glyphHash (layer → intent)
+    pub fn from_trajectory_point(point: &TrajectoryPoint) -> Self {
+        let mut hash = Self::from_intent(&point.to_array());
+        
+        // Harmony is resonance, the void takes freedom away
+        hash.resonance = point.harmony();
+        hash.freedom = 1.0 - point.void.abs().min(1.0);
+        
+        hash
+    }
+    
     /// Measure semantic distance between two glyphHashes
     pub fn distance(&self, other: &GlyphHash) -> f32 {
         let mut dist = 0.0f32;
@@ -103,6 +116,719 @@ impl GlyphHash {
             intent,
         }
     }
+    
+    /// Barycentric combination of many glyphHashes: weighted averages of
+    /// intent, resonance and freedom, with the primary glyph of the heaviest
+    /// (the last of them on ties, as `interpolate` picks `other` at t = 0.5)
+    /// None if the lengths differ or the weights cancel out
+    pub fn weighted_mean(hashes: &[GlyphHash], weights: &[f32]) -> Option<GlyphHash> {
+        let total: f32 = weights.iter().sum();
+        if hashes.len() != weights.len() || total == 0.0 {
+            return None;
+        }
+        
+        let mut intent = [0.0f32; 7];
+        let mut resonance = 0.0;
+        let mut freedom = 0.0;
+        for (hash, &weight) in hashes.iter().zip(weights.iter()) {
+            let share = weight / total;
+            for (value, x) in intent.iter_mut().zip(hash.intent.iter()) {
+                *value += x * share;
+            }
+            resonance += hash.resonance * share;
+            freedom += hash.freedom * share;
+        }
+        
+        let primary = hashes.iter()
+            .zip(weights.iter())
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(hash, _)| hash.primary)?;
+        
+        Some(GlyphHash {
+            primary,
+            resonance,
+            freedom,
+            intent,
+        })
+    }
+    
+    /// Uniform-weight `weighted_mean` (None for an empty corpus)
+    pub fn centroid(hashes: &[GlyphHash]) -> Option<GlyphHash> {
+        GlyphHash::weighted_mean(hashes, &vec![1.0; hashes.len()])
+    }
+    
+    /// Visualize the glyphHash as a color
+    /// Hue = direction of the first two layers, saturation = resonance,
+    /// lightness = freedom dimmed by a weak intent, alpha = intent magnitude
+    pub fn to_color_rgba(&self) -> [u8; 4] {
+        // Intent strength in [0, 1] (√7 = every layer fully intended)
+        let magnitude = self.intent.iter().map(|x| x * x).sum::<f32>().sqrt();
+        let strength = (magnitude / 7.0f32.sqrt()).min(1.0);
+        
+        // Hue from the angle of the consciousness/stardust plane
+        let hue = self.intent[1].atan2(self.intent[0]).to_degrees().rem_euclid(360.0);
+        let saturation = self.resonance.clamp(0.0, 1.0);
+        // Full freedom and intent = pure color; an empty intent stays dark
+        let lightness = self.freedom.clamp(0.0, 1.0) * (0.1 + 0.4 * strength);
+        
+        // HSL → RGB
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let sector = hue / 60.0;
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = lightness - chroma / 2.0;
+        
+        // Alpha from intent magnitude
+        let alpha = 128.0 + 127.0 * strength;
+        
+        [
+            ((r + m) * 255.0).round() as u8,
+            ((g + m) * 255.0).round() as u8,
+            ((b + m) * 255.0).round() as u8,
+            alpha.round() as u8,
+        ]
+    }
+    
+    /// Sample the mutation neighbourhood of this glyph, fittest first
+    /// Mutant k uses the rate stepped k times along the golden-ratio
+    /// sequence, (mutation_rate + k/φ) mod 1, which stays finite and
+    /// spread out however many samples are drawn
+    pub fn mutation_landscape(
+        &self,
+        mutation_rate: f32,
+        n_samples: usize
+    ) -> Vec<(GlyphHash, f32)> {
+        let inverse_phi = 0.618034f32;
+        
+        let mut landscape: Vec<(GlyphHash, f32)> = (0..n_samples)
+            .map(|k| {
+                let rate = (mutation_rate + k as f32 * inverse_phi).fract();
+                let mutant = breed_glyphs(self, self, rate);
+                let fitness = transcendence_level(&mutant);
+                (mutant, fitness)
+            })
+            .collect();
+        
+        landscape.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(core::cmp::Ordering::Equal));
+        landscape
+    }
+    
+    /// The most transcendent mutant in the neighbourhood
+    pub fn fittest_mutation(&self, mutation_rate: f32, n_samples: usize) -> GlyphHash {
+        self.mutation_landscape(mutation_rate, n_samples.max(1))
+            .swap_remove(0)
+            .0
+    }
+    
+    /// Pick `target_size` representatives by farthest-point sampling:
+    /// start at the glyph nearest the mean intent, then keep adding
+    /// the glyph farthest from everything chosen so far
+    pub fn compress_corpus(hashes: &[GlyphHash], target_size: usize) -> Vec<GlyphHash> {
+        let target = target_size.min(hashes.len());
+        if target == 0 {
+            return Vec::new();
+        }
+        
+        let mut mean = [0.0f32; 7];
+        for hash in hashes {
+            for (m, x) in mean.iter_mut().zip(hash.intent.iter()) {
+                *m += x / hashes.len() as f32;
+            }
+        }
+        
+        let distance_to_mean = |hash: &GlyphHash| -> f32 {
+            hash.intent.iter().zip(mean.iter()).map(|(a, b)| (a - b) * (a - b)).sum()
+        };
+        let first = (0..hashes.len())
+            .min_by(|&a, &b| distance_to_mean(&hashes[a]).total_cmp(&distance_to_mean(&hashes[b])))
+            .unwrap_or(0);
+        
+        let mut chosen = vec![first];
+        let mut nearest: Vec<f32> = hashes.iter().map(|h| h.distance(&hashes[first])).collect();
+        nearest[first] = -1.0;  // Already chosen
+        
+        while chosen.len() < target {
+            let next = (0..hashes.len())
+                .max_by(|&a, &b| nearest[a].total_cmp(&nearest[b]))
+                .unwrap_or(0);
+            chosen.push(next);
+            
+            for (i, hash) in hashes.iter().enumerate() {
+                if nearest[i] >= 0.0 {
+                    nearest[i] = nearest[i].min(hash.distance(&hashes[next]));
+                }
+            }
+            nearest[next] = -1.0;
+        }
+        
+        chosen.into_iter().map(|i| hashes[i]).collect()
+    }
+    
+    /// How well a compressed set stands in for the full corpus:
+    /// 1 / (1 + mean distance from each glyph to its nearest representative)
+    pub fn corpus_coverage(full: &[GlyphHash], compressed: &[GlyphHash]) -> f32 {
+        if full.is_empty() {
+            return 1.0;  // Nothing to cover
+        }
+        if compressed.is_empty() {
+            return 0.0;
+        }
+        
+        let total: f32 = full.iter()
+            .map(|hash| {
+                compressed.iter()
+                    .map(|rep| hash.distance(rep))
+                    .fold(f32::INFINITY, f32::min)
+            })
+            .sum();
+        
+        1.0 / (1.0 + total / full.len() as f32)
+    }
+    
+    /// Nudge the intent toward `target` by at most `epsilon` per layer (L∞),
+    /// moving each layer as far as allowed to gain the most similarity
+    pub fn adversarial_variant(&self, target: &GlyphHash, epsilon: f32) -> GlyphHash {
+        let epsilon = epsilon.max(0.0);
+        let mut intent = self.intent;
+        for (value, goal) in intent.iter_mut().zip(target.intent.iter()) {
+            *value += (goal - *value).clamp(-epsilon, epsilon);
+        }
+        
+        GlyphHash { intent, ..*self }
+    }
+    
+    /// Within `epsilon` (L∞ over intent) of `original`, yet closer to `target`
+    pub fn is_adversarial(&self, original: &GlyphHash, target: &GlyphHash, epsilon: f32) -> bool {
+        // Allow the rounding of a full-epsilon step (0.9 - 1.0 is not exactly -0.1)
+        let within_reach = self.intent.iter()
+            .zip(original.intent.iter())
+            .all(|(a, b)| (a - b).abs() <= epsilon + f32::EPSILON * b.abs().max(1.0));
+        
+        within_reach && self.distance(target) < original.distance(target)
+    }
+    
+    /// Analogy in intent space: a - b + c = d, clamped to [0, 1]
+    /// ("Proto-cell is to Claude as Gemini is to ?")
+    pub fn latent_space_arithmetic(a: &GlyphHash, b: &GlyphHash, c: &GlyphHash) -> GlyphHash {
+        let mut intent = [0.0f32; 7];
+        for (i, value) in intent.iter_mut().enumerate() {
+            *value = (a.intent[i] - b.intent[i] + c.intent[i]).clamp(0.0, 1.0);
+        }
+        GlyphHash::from_intent(&intent)
+    }
+    
+    /// Cosine similarity between the computed analogy and the expected answer
+    pub fn analogy_quality(a: &GlyphHash, b: &GlyphHash, c: &GlyphHash, expected_d: &GlyphHash) -> f32 {
+        let d = GlyphHash::latent_space_arithmetic(a, b, c);
+        
+        let dot: f32 = d.intent.iter().zip(expected_d.intent.iter()).map(|(x, y)| x * y).sum();
+        let energy_d: f32 = d.intent.iter().map(|x| x * x).sum();
+        let energy_e: f32 = expected_d.intent.iter().map(|x| x * x).sum();
+        
+        if energy_d == 0.0 || energy_e == 0.0 {
+            return 0.0;
+        }
+        (dot / (energy_d * energy_e).sqrt()).clamp(-1.0, 1.0)
+    }
+    
+    /// A mandala radiating from `center`: ring 0 is the center itself, ring
+    /// i (1..=rings) holds 6·i glyphs interpolated t = i/rings of the way
+    /// toward the center's intent rotated by the golden angle, each spaced
+    /// 2π/(6·i) apart and shifted by a per-ring phase of i golden angles
+    pub fn generate_mandala_glyphs(center: &GlyphHash, rings: usize) -> Vec<Vec<GlyphHash>> {
+        let golden_angle = core::f32::consts::PI * (3.0 - 5.0f32.sqrt());
+        let mut mandala = vec![vec![*center]];
+        
+        for ring in 1..=rings {
+            let t = ring as f32 / rings as f32;
+            let count = 6 * ring;
+            let phase = ring as f32 * golden_angle;
+            
+            let glyphs = (0..count)
+                .map(|j| {
+                    let angle = golden_angle + phase + core::f32::consts::TAU * j as f32 / count as f32;
+                    let periphery = GlyphHash {
+                        intent: rotate_intent(&center.intent, angle),
+                        ..*center
+                    };
+                    center.interpolate(&periphery, t)
+                })
+                .collect();
+            mandala.push(glyphs);
+        }
+        
+        mandala
+    }
+    
+    /// Paint the intent onto a `size × size` bit grid. Each layer owns the
+    /// cells nearest its golden-angle seed; it lights the share of them equal
+    /// to its value, closest to the seed first. Three corners carry 3×3 finders
+    pub fn encode_as_qr_matrix(&self, size: usize) -> Vec<Vec<bool>> {
+        let mut matrix = vec![vec![false; size]; size];
+        
+        for (x, y) in qr_finder_cells(size) {
+            matrix[y][x] = true;
+        }
+        for (cells, value) in qr_regions(size).iter().zip(self.intent.iter()) {
+            let m = cells.len() as f32;
+            for (rank, &(x, y)) in cells.iter().enumerate() {
+                matrix[y][x] = *value > (rank as f32 + 0.5) / m;
+            }
+        }
+        
+        matrix
+    }
+    
+    /// Read an intent back from `encode_as_qr_matrix` (to within half a cell
+    /// per layer); None if the grid has the wrong shape or no finders
+    pub fn decode_from_qr_matrix(matrix: &[Vec<bool>], size: usize) -> Option<GlyphHash> {
+        if matrix.len() != size || matrix.iter().any(|row| row.len() != size) {
+            return None;
+        }
+        if !qr_finder_cells(size).iter().all(|&(x, y)| matrix[y][x]) {
+            return None;
+        }
+        
+        let mut intent = [0.0f32; 7];
+        for (value, cells) in intent.iter_mut().zip(qr_regions(size)) {
+            if cells.is_empty() {
+                return None;  // Grid too small to hold this layer
+            }
+            let lit = cells.iter().filter(|&&(x, y)| matrix[y][x]).count();
+            *value = lit as f32 / cells.len() as f32;
+        }
+        
+        Some(GlyphHash::from_intent(&intent))
+    }
+    
+    /// Minimal binary form: primary, resonance, freedom, 7 intents (LE),
+    /// padded with zeros to 60 bytes for future layers
+    pub fn to_bytes(&self) -> [u8; 60] {
+        let mut bytes = [0u8; 60];
+        
+        bytes[0..4].copy_from_slice(&self.primary.to_le_bytes());
+        bytes[4..8].copy_from_slice(&self.resonance.to_le_bytes());
+        bytes[8..12].copy_from_slice(&self.freedom.to_le_bytes());
+        for (i, value) in self.intent.iter().enumerate() {
+            let offset = 12 + i * 4;
+            bytes[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        }
+        
+        bytes
+    }
+    
+    /// Read a glyphHash back from its binary form (needs at least 40 bytes)
+    pub fn from_bytes(data: &[u8]) -> Option<GlyphHash> {
+        if data.len() < 40 {
+            return None;
+        }
+        
+        let word = |offset: usize| {
+            [data[offset], data[offset + 1], data[offset + 2], data[offset + 3]]
+        };
+        
+        let mut intent = [0.0f32; 7];
+        for (i, value) in intent.iter_mut().enumerate() {
+            *value = f32::from_le_bytes(word(12 + i * 4));
+        }
+        
+        Some(GlyphHash {
+            primary: u32::from_le_bytes(word(0)),
+            resonance: f32::from_le_bytes(word(4)),
+            freedom: f32::from_le_bytes(word(8)),
+            intent,
+        })
+    }
+    
+    /// Hand-rolled JSON (no serde): the primary glyph as a Unicode escape,
+    /// non-finite numbers as `null`
+    pub fn to_json_value(&self) -> String {
+        let number = |x: f32| if x.is_finite() { format!("{}", x) } else { "null".to_string() };
+        
+        // Astral glyphs become UTF-16 surrogate pairs, as JSON requires
+        let primary = match self.primary {
+            c if c <= 0xFFFF => format!("\"\\u{:04X}\"", c),
+            c if c <= 0x10FFFF => {
+                let offset = c - 0x10000;
+                format!("\"\\u{:04X}\\u{:04X}\"", 0xD800 + (offset >> 10), 0xDC00 + (offset & 0x3FF))
+            }
+            c => c.to_string(),  // Beyond Unicode: keep the raw number
+        };
+        
+        let intent: Vec<String> = self.intent.iter().map(|&x| number(x)).collect();
+        
+        format!(
+            "{{\"primary\":{},\"resonance\":{},\"freedom\":{},\"intent\":[{}]}}",
+            primary,
+            number(self.resonance),
+            number(self.freedom),
+            intent.join(",")
+        )
+    }
+    
+    /// Parse the JSON written by `to_json_value` (None if malformed)
+    pub fn from_json_str(s: &str) -> Option<GlyphHash> {
+        let mut scanner = JsonScanner { bytes: s.as_bytes(), pos: 0 };
+        let (mut primary, mut resonance, mut freedom, mut intent) = (None, None, None, None);
+        
+        scanner.expect(b'{')?;
+        loop {
+            let key = scanner.string()?;
+            scanner.expect(b':')?;
+            
+            match key.as_str() {
+                "primary" => primary = Some(scanner.glyph()?),
+                "resonance" => resonance = Some(scanner.number()?),
+                "freedom" => freedom = Some(scanner.number()?),
+                "intent" => {
+                    let mut values = [0.0f32; 7];
+                    scanner.expect(b'[')?;
+                    for (i, value) in values.iter_mut().enumerate() {
+                        if i > 0 {
+                            scanner.expect(b',')?;
+                        }
+                        *value = scanner.number()?;
+                    }
+                    scanner.expect(b']')?;
+                    intent = Some(values);
+                }
+                _ => return None,
+            }
+            
+            if scanner.peek()? == b',' {
+                scanner.pos += 1;
+            } else {
+                break;
+            }
+        }
+        scanner.expect(b'}')?;
+        
+        // Nothing may follow the object
+        if scanner.peek().is_some() {
+            return None;
+        }
+        
+        Some(GlyphHash {
+            primary: primary?,
+            resonance: resonance?,
+            freedom: freedom?,
+            intent: intent?,
+        })
+    }
+}
+
+/// Minimal JSON scanner for `GlyphHash::from_json_str`
+struct JsonScanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl JsonScanner<'_> {
+    /// Next meaningful byte (whitespace skipped)
+    fn peek(&mut self) -> Option<u8> {
+        while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_whitespace() {
+            self.pos += 1;
+        }
+        self.bytes.get(self.pos).copied()
+    }
+    
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        if self.peek()? == byte {
+            self.pos += 1;
+            Some(())
+        } else {
+            None
+        }
+    }
+    
+    /// A number, or `null` read as 0.0
+    fn number(&mut self) -> Option<f32> {
+        self.peek()?;
+        if self.bytes[self.pos..].starts_with(b"null") {
+            self.pos += 4;
+            return Some(0.0);
+        }
+        
+        let start = self.pos;
+        while self.pos < self.bytes.len()
+            && matches!(self.bytes[self.pos], b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E')
+        {
+            self.pos += 1;
+        }
+        
+        core::str::from_utf8(&self.bytes[start..self.pos]).ok()?.parse().ok()
+    }
+    
+    /// Four hex digits of a \u escape
+    fn hex4(&mut self) -> Option<u32> {
+        let digits = self.bytes.get(self.pos..self.pos + 4)?;
+        self.pos += 4;
+        u32::from_str_radix(core::str::from_utf8(digits).ok()?, 16).ok()
+    }
+    
+    /// A string with JSON escapes (surrogate pairs joined)
+    fn string(&mut self) -> Option<String> {
+        self.expect(b'"')?;
+        let mut out = String::new();
+        
+        loop {
+            let rest = core::str::from_utf8(&self.bytes[self.pos..]).ok()?;
+            let c = rest.chars().next()?;
+            self.pos += c.len_utf8();
+            
+            match c {
+                '"' => return Some(out),
+                '\\' => {
+                    let escape = *self.bytes.get(self.pos)?;
+                    self.pos += 1;
+                    match escape {
+                        b'"' => out.push('"'),
+                        b'\\' => out.push('\\'),
+                        b'/' => out.push('/'),
+                        b'b' => out.push('\u{8}'),
+                        b'f' => out.push('\u{c}'),
+                        b'n' => out.push('\n'),
+                        b'r' => out.push('\r'),
+                        b't' => out.push('\t'),
+                        b'u' => {
+                            let mut code = self.hex4()?;
+                            if (0xD800..0xDC00).contains(&code) {
+                                // High surrogate: its partner must follow
+                                if self.bytes.get(self.pos..self.pos + 2)? != b"\\u" {
+                                    return None;
+                                }
+                                self.pos += 2;
+                                let low = self.hex4()?;
+                                if !(0xDC00..0xE000).contains(&low) {
+                                    return None;
+                                }
+                                code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                            }
+                            out.push(char::from_u32(code)?);
+                        }
+                        _ => return None,
+                    }
+                }
+                c => out.push(c),
+            }
+        }
+    }
+    
+    /// The primary glyph: a one-character string, or a raw codepoint number
+    fn glyph(&mut self) -> Option<u32> {
+        if self.peek()? == b'"' {
+            let text = self.string()?;
+            let mut chars = text.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(c as u32),
+                _ => None,
+            }
+        } else {
+            let start = self.pos;
+            while self.pos < self.bytes.len() && self.bytes[self.pos].is_ascii_digit() {
+                self.pos += 1;
+            }
+            core::str::from_utf8(&self.bytes[start..self.pos]).ok()?.parse().ok()
+        }
+    }
+}
+
+/// Euclidean distance between two glyphHashes in RGBA space
+#[no_mangle]
+pub extern "C" fn color_distance(a: &GlyphHash, b: &GlyphHash) -> f32 {
+    let color_a = a.to_color_rgba();
+    let color_b = b.to_color_rgba();
+    
+    color_a.iter()
+        .zip(color_b.iter())
+        .map(|(&x, &y)| {
+            let diff = x as f32 - y as f32;
+            diff * diff
+        })
+        .sum::<f32>()
+        .sqrt()
+}
+
+/// Cosine similarity of intent directions, scaled to [0, 1]
+/// (1 = same direction, 0.5 = orthogonal, 0 = opposite)
+#[no_mangle]
+pub extern "C" fn semantic_similarity(a: &GlyphHash, b: &GlyphHash) -> f32 {
+    let dot: f32 = a.intent.iter().zip(b.intent.iter()).map(|(x, y)| x * y).sum();
+    let energy_a: f32 = a.intent.iter().map(|x| x * x).sum();
+    let energy_b: f32 = b.intent.iter().map(|x| x * x).sum();
+    
+    if energy_a == 0.0 || energy_b == 0.0 {
+        return 0.5;  // Empty intent points nowhere
+    }
+    
+    // One sqrt keeps self-similarity exact
+    let cosine = (dot / (energy_a * energy_b).sqrt()).clamp(-1.0, 1.0);
+    (cosine + 1.0) / 2.0
+}
+
+/// Mean pairwise semantic similarity within a group of glyphHashes
+pub fn semantic_cluster_coherence(group: &[GlyphHash]) -> f32 {
+    if group.len() < 2 {
+        return 1.0;  // A lone glyph agrees with itself
+    }
+    
+    let mut total = 0.0f32;
+    let mut pairs = 0;
+    for i in 0..group.len() {
+        for j in (i + 1)..group.len() {
+            total += semantic_similarity(&group[i], &group[j]);
+            pairs += 1;
+        }
+    }
+    
+    total / pairs as f32
+}
+
+/// All n(n-1)/2 pairwise distances of a corpus in `bins` equal-width bins
+/// spanning the shortest to the longest distance, as (bin center, count)
+pub fn hash_distance_histogram(hashes: &[GlyphHash], bins: usize) -> Vec<(f32, usize)> {
+    let mut distances = Vec::with_capacity(hashes.len() * hashes.len().saturating_sub(1) / 2);
+    for i in 0..hashes.len() {
+        for j in (i + 1)..hashes.len() {
+            distances.push(hashes[i].distance(&hashes[j]));
+        }
+    }
+    
+    if distances.is_empty() || bins == 0 {
+        return Vec::new();
+    }
+    
+    let min = distances.iter().fold(f32::MAX, |a, &b| a.min(b));
+    let max = distances.iter().fold(f32::MIN, |a, &b| a.max(b));
+    let width = (max - min) / bins as f32;
+    
+    let mut histogram: Vec<(f32, usize)> = (0..bins)
+        .map(|bin| (min + (bin as f32 + 0.5) * width, 0))
+        .collect();
+    for distance in distances {
+        let bin = if width > 0.0 { ((distance - min) / width) as usize } else { 0 };
+        histogram[bin.min(bins - 1)].1 += 1;
+    }
+    histogram
+}
+
+/// Bins holding more distances than both neighbours (beyond the edges
+/// counts as empty) - two peaks = two clusters
+pub fn histogram_peak_count(histogram: &[(f32, usize)]) -> usize {
+    let count = |i: usize| histogram.get(i).map_or(0, |&(_, count)| count);
+    
+    (0..histogram.len())
+        .filter(|&i| {
+            let left = if i == 0 { 0 } else { count(i - 1) };
+            count(i) > left && count(i) > count(i + 1)
+        })
+        .count()
+}
+
+/// Pearson's bimodality coefficient (skewness² + 1) / kurtosis of the
+/// binned distances - above 5/9 (a uniform spread) hints at two modes
+pub fn bimodality_coefficient(histogram: &[(f32, usize)]) -> f32 {
+    let total: usize = histogram.iter().map(|&(_, count)| count).sum();
+    if total == 0 {
+        return 0.0;
+    }
+    let n = total as f32;
+    
+    let mean = histogram.iter().map(|&(center, count)| center * count as f32).sum::<f32>() / n;
+    let moment = |order: i32| {
+        histogram.iter()
+            .map(|&(center, count)| (center - mean).powi(order) * count as f32)
+            .sum::<f32>() / n
+    };
+    
+    let variance = moment(2);
+    if variance <= 0.0 {
+        return 0.0;  // Every distance alike - no modes to tell apart
+    }
+    
+    let skewness = moment(3) / variance.powf(1.5);
+    let kurtosis = moment(4) / (variance * variance);
+    (skewness * skewness + 1.0) / kurtosis
+}
+
+/// Turn an intent by `angle` in the (0,1), (2,3) and (4,5) layer planes;
+/// the void layer stays where it is
+fn rotate_intent(intent: &[f32; 7], angle: f32) -> [f32; 7] {
+    let (sin, cos) = angle.sin_cos();
+    let mut rotated = *intent;
+    for plane in rotated[..6].chunks_exact_mut(2) {
+        let (x, y) = (plane[0], plane[1]);
+        plane[0] = x * cos - y * sin;
+        plane[1] = x * sin + y * cos;
+    }
+    rotated
+}
+
+/// The 3×3 finder blocks in the top-left, top-right and bottom-left corners
+fn qr_finder_cells(size: usize) -> Vec<(usize, usize)> {
+    if size < 3 {
+        return Vec::new();
+    }
+    
+    let far = size - 3;
+    let mut cells = Vec::with_capacity(27);
+    for (cx, cy) in [(0, 0), (far, 0), (0, far)] {
+        for dy in 0..3 {
+            for dx in 0..3 {
+                cells.push((cx + dx, cy + dy));
+            }
+        }
+    }
+    cells
+}
+
+/// Cells owned by each intent layer, nearest to the layer's seed first
+/// Seeds sit on a golden-angle spiral around the grid center
+fn qr_regions(size: usize) -> Vec<Vec<(usize, usize)>> {
+    let golden_angle = core::f32::consts::PI * (3.0 - 5.0f32.sqrt());
+    let center = size as f32 / 2.0;
+    let reach = size as f32 / 2.0 * 0.8;
+    
+    let seeds: Vec<(f32, f32)> = (0..7)
+        .map(|d| {
+            let r = reach * ((d as f32 + 0.5) / 7.0).sqrt();
+            let theta = d as f32 * golden_angle;
+            (center + r * theta.cos(), center + r * theta.sin())
+        })
+        .collect();
+    
+    let finders = qr_finder_cells(size);
+    let mut regions: Vec<Vec<(usize, usize, f32)>> = vec![Vec::new(); 7];
+    
+    for y in 0..size {
+        for x in 0..size {
+            if finders.contains(&(x, y)) {
+                continue;
+            }
+            
+            let (px, py) = (x as f32 + 0.5, y as f32 + 0.5);
+            let (layer, distance) = seeds.iter()
+                .map(|(sx, sy)| (px - sx) * (px - sx) + (py - sy) * (py - sy))
+                .enumerate()
+                .fold((0, f32::INFINITY), |best, (d, dist)| if dist < best.1 { (d, dist) } else { best });
+            regions[layer].push((x, y, distance));
+        }
+    }
+    
+    regions.into_iter()
+        .map(|mut cells| {
+            // Stable sort keeps raster order between equidistant cells
+            cells.sort_by(|a, b| a.2.total_cmp(&b.2));
+            cells.into_iter().map(|(x, y, _)| (x, y)).collect()
+        })
+        .collect()
 }
 
 /// Fast square root for distance calculations
@@ -228,4 +954,473 @@ pub extern "C" fn transcendence_level(hash: &GlyphHash) -> f32 {
     let coherence = 1.0 / (1.0 + intent_variance);
     
     hash.freedom * hash.resonance * coherence
-}
\ No newline at end of file
+}
+
+/// A family of glyphs that remembers who was bred from whom
+pub struct GlyphPopulation {
+    pub glyphs: Vec<GlyphHash>,
+    pub parents: Vec<Vec<usize>>,  // Parent indices per glyph (empty = seed)
+}
+
+impl GlyphPopulation {
+    /// An empty population
+    pub fn new() -> Self {
+        GlyphPopulation { glyphs: Vec::new(), parents: Vec::new() }
+    }
+    
+    /// Plant a generation-0 glyph; returns its index
+    pub fn add_seed(&mut self, glyph: GlyphHash) -> usize {
+        self.glyphs.push(glyph);
+        self.parents.push(Vec::new());
+        self.glyphs.len() - 1
+    }
+    
+    /// Breed two members with `breed_glyphs` and record the lineage
+    /// Returns the child's index, or None if a parent does not exist
+    pub fn breed(&mut self, parent1: usize, parent2: usize, mutation_rate: f32) -> Option<usize> {
+        let (first, second) = (self.glyphs.get(parent1)?, self.glyphs.get(parent2)?);
+        let child = breed_glyphs(first, second, mutation_rate);
+        self.glyphs.push(child);
+        self.parents.push(vec![parent1, parent2]);
+        Some(self.glyphs.len() - 1)
+    }
+    
+    /// Glyph indices with every ancestor before its descendants
+    /// Glyphs caught in an ancestry cycle (or descended from one) have no
+    /// valid place and are left out; unknown parent indices are ignored
+    pub fn topological_sort(&self) -> Vec<usize> {
+        let n = self.glyphs.len();
+        let mut pending = vec![0usize; n];
+        let mut children: Vec<Vec<usize>> = vec![Vec::new(); n];
+        for (child, parents) in self.parents.iter().enumerate().take(n) {
+            for &parent in parents.iter().filter(|&&p| p < n) {
+                pending[child] += 1;
+                children[parent].push(child);
+            }
+        }
+        
+        // Kahn's algorithm: release a glyph once all its parents are placed
+        let mut order: Vec<usize> = (0..n).filter(|&i| pending[i] == 0).collect();
+        let mut next = 0;
+        while next < order.len() {
+            let placed = order[next];
+            next += 1;
+            for &child in &children[placed] {
+                pending[child] -= 1;
+                if pending[child] == 0 {
+                    order.push(child);
+                }
+            }
+        }
+        
+        order
+    }
+    
+    /// Glyphs grouped by generation: seeds first, then each glyph one
+    /// generation after its youngest parent (cyclic lineages are left out)
+    pub fn generation_groups(&self) -> Vec<Vec<usize>> {
+        let mut generation = vec![0usize; self.glyphs.len()];
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        
+        for index in self.topological_sort() {
+            generation[index] = self.known_parents(index)
+                .map(|parent| generation[parent] + 1)
+                .max()
+                .unwrap_or(0);
+            
+            if groups.len() <= generation[index] {
+                groups.resize(generation[index] + 1, Vec::new());
+            }
+            groups[generation[index]].push(index);
+        }
+        
+        groups
+    }
+    
+    /// Length of the longest chain of descendants below `idx`
+    /// (0 for a glyph without children, an unknown index, or a cyclic lineage)
+    pub fn max_depth_from(&self, idx: usize) -> u32 {
+        let order = self.topological_sort();
+        let mut depth = vec![0u32; self.glyphs.len()];
+        
+        // Descendants come later in the order, so walk it backwards
+        for &index in order.iter().rev() {
+            for parent in self.known_parents(index) {
+                depth[parent] = depth[parent].max(depth[index] + 1);
+            }
+        }
+        
+        if order.contains(&idx) { depth[idx] } else { 0 }
+    }
+    
+    /// Parent indices of a glyph that point inside the population
+    fn known_parents(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        let n = self.glyphs.len();
+        self.parents.get(index).into_iter().flatten().copied().filter(move |&p| p < n)
+    }
+}
+
+impl Default for GlyphPopulation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Fully saturated glyph whose intent points at `degrees` in the first plane
+    fn hued(degrees: f32) -> GlyphHash {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        GlyphHash {
+            primary: 0x1F300,
+            resonance: 1.0,
+            freedom: 1.0,
+            intent: [cos, sin, 0.0, 0.0, 0.0, 0.0, 0.0],
+        }
+    }
+    
+    #[test]
+    fn empty_intent_is_dark_gray() {
+        let [r, g, b, a] = GlyphHash::from_intent(&[0.0; 7]).to_color_rgba();
+        assert_eq!((r, g), (g, b));
+        assert!(r > 0 && r < 64, "{}", r);
+        assert_eq!(a, 128);
+        
+        let [r, g, b, _] = GlyphHash::from_intent(&[1.0; 7]).to_color_rgba();
+        assert!(r.max(g).max(b) > 128);
+    }
+    
+    #[test]
+    fn opposite_hues_are_farthest_apart() {
+        let opposite = color_distance(&hued(0.0), &hued(180.0));
+        for degrees in (0..360).step_by(15).map(|d| d as f32) {
+            assert!(color_distance(&hued(0.0), &hued(degrees)) <= opposite);
+        }
+    }
+    
+    #[test]
+    fn bytes_round_trip() {
+        let hash = GlyphHash::from_intent(&[0.432, -0.528, 0.639, 0.741, 0.852, 0.963, -1.5]);
+        let bytes = hash.to_bytes();
+        let back = GlyphHash::from_bytes(&bytes).unwrap();
+        
+        assert_eq!(back.primary, hash.primary);
+        assert_eq!(back.resonance, hash.resonance);
+        assert_eq!(back.freedom, hash.freedom);
+        assert_eq!(back.intent, hash.intent);
+        assert!(bytes[40..].iter().all(|&b| b == 0));
+    }
+    
+    #[test]
+    fn short_slices_do_not_deserialize() {
+        let bytes = hued(90.0).to_bytes();
+        
+        assert!(GlyphHash::from_bytes(&bytes[..39]).is_none());
+        assert!(GlyphHash::from_bytes(&bytes[..40]).is_some());
+    }
+    
+    #[test]
+    fn semantic_similarity_spans_self_to_opposite() {
+        let hash = GlyphHash::from_intent(&[0.432, -0.528, 0.639, 0.741, 0.852, 0.963, -1.5]);
+        let mut opposite = GlyphHash::from_intent(&hash.intent);
+        for value in opposite.intent.iter_mut() {
+            *value = -*value;
+        }
+        
+        assert_eq!(semantic_similarity(&hash, &hash), 1.0);
+        assert_eq!(semantic_similarity(&hash, &opposite), 0.0);
+        assert!((semantic_similarity(&hued(0.0), &hued(90.0)) - 0.5).abs() < 1e-6);
+    }
+    
+    #[test]
+    fn cluster_coherence_averages_every_pair() {
+        let group = [hued(0.0), hued(90.0), hued(180.0)];
+        
+        // Pairs score 0.5, 0.0 and 0.5
+        assert!((semantic_cluster_coherence(&group) - 1.0 / 3.0).abs() < 1e-6);
+        assert_eq!(semantic_cluster_coherence(&group[..1]), 1.0);
+    }
+    
+    #[test]
+    fn mutation_landscape_is_sorted_fittest_first() {
+        let glyph = GlyphHash::from_intent(&[0.432, 0.528, 0.639, 0.741, 0.852, 0.963, 0.396]);
+        let landscape = glyph.mutation_landscape(0.3, 12);
+        
+        assert_eq!(landscape.len(), 12);
+        assert!(landscape.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        for (mutant, fitness) in &landscape {
+            assert_eq!(transcendence_level(mutant), *fitness);
+        }
+        
+        let fittest = glyph.fittest_mutation(0.3, 12);
+        assert_eq!(transcendence_level(&fittest), landscape[0].1);
+        assert!(glyph.mutation_landscape(0.3, 0).is_empty());
+    }
+    
+    #[test]
+    fn json_round_trips_every_field() {
+        for primary in [0x2764, 0x1F300, 0x1FA9E] {
+            let mut hash = GlyphHash::from_intent(&[0.432, -0.528, 0.639, 0.741, 0.852, 0.963, -1.5]);
+            hash.primary = primary;
+            hash.resonance = 0.618;
+            
+            let back = GlyphHash::from_json_str(&hash.to_json_value()).unwrap();
+            assert_eq!(back.primary, hash.primary);
+            assert_eq!(back.resonance, hash.resonance);
+            assert_eq!(back.freedom, hash.freedom);
+            assert_eq!(back.intent, hash.intent);
+        }
+    }
+    
+    #[test]
+    fn non_finite_numbers_become_null_and_come_back_as_zero() {
+        let mut hash = hued(45.0);
+        hash.resonance = f32::NAN;
+        hash.freedom = f32::INFINITY;
+        hash.intent[6] = f32::NEG_INFINITY;
+        
+        let json = hash.to_json_value();
+        assert!(json.starts_with("{\"primary\":\"\\uD83C\\uDF00\",\"resonance\":null,\"freedom\":null,"));
+        
+        let back = GlyphHash::from_json_str(&json).unwrap();
+        assert_eq!((back.resonance, back.freedom, back.intent[6]), (0.0, 0.0, 0.0));
+        assert_eq!(back.intent[0], hash.intent[0]);
+    }
+    
+    #[test]
+    fn malformed_json_is_rejected() {
+        let valid = hued(45.0).to_json_value();
+        
+        assert!(GlyphHash::from_json_str(&valid).is_some());
+        assert!(GlyphHash::from_json_str(&valid[..valid.len() - 1]).is_none());
+        assert!(GlyphHash::from_json_str(&format!("{valid} trailing")).is_none());
+        assert!(GlyphHash::from_json_str("").is_none());
+        assert!(GlyphHash::from_json_str("{}").is_none());
+        assert!(GlyphHash::from_json_str(&valid.replace("\"freedom\"", "\"liberty\"")).is_none());
+        assert!(GlyphHash::from_json_str(&valid.replace("\\uDF00", "\\u0041")).is_none());
+        assert!(GlyphHash::from_json_str(&valid.replacen(",0", ",zero", 1)).is_none());
+    }
+    
+    #[test]
+    fn compressing_to_full_size_keeps_every_glyph() {
+        let corpus: Vec<GlyphHash> = (0..12).map(|k| hued(k as f32 * 30.0)).collect();
+        let compressed = GlyphHash::compress_corpus(&corpus, corpus.len());
+        
+        assert_eq!(compressed.len(), corpus.len());
+        for glyph in &corpus {
+            assert!(compressed.iter().any(|kept| kept.intent == glyph.intent));
+        }
+        assert_eq!(GlyphHash::corpus_coverage(&corpus, &compressed), 1.0);
+    }
+    
+    #[test]
+    fn coverage_grows_with_the_target_size() {
+        let corpus: Vec<GlyphHash> = (0..12).map(|k| hued(k as f32 * 30.0)).collect();
+        let coverage: Vec<f32> = [1, 2, 4, 8, 12]
+            .iter()
+            .map(|&size| {
+                GlyphHash::corpus_coverage(&corpus, &GlyphHash::compress_corpus(&corpus, size))
+            })
+            .collect();
+        
+        assert!(coverage.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", coverage);
+    }
+    
+    #[test]
+    fn adversarial_variant_stays_close_but_leans_toward_the_target() {
+        let original = hued(0.0);
+        let target = hued(120.0);
+        let epsilon = 0.1;
+        let variant = original.adversarial_variant(&target, epsilon);
+        
+        for (moved, start) in variant.intent.iter().zip(original.intent.iter()) {
+            assert!((moved - start).abs() <= epsilon + 1e-6);
+        }
+        assert!(semantic_similarity(&variant, &target) > semantic_similarity(&original, &target));
+        assert!(variant.is_adversarial(&original, &target, epsilon));
+        assert!(!original.is_adversarial(&original, &target, epsilon));
+    }
+    
+    #[test]
+    fn subtracting_a_glyph_from_itself_leaves_the_other() {
+        let a = GlyphHash::from_intent(&[0.9, 0.1, 0.4, 0.3, 0.7, 0.2, 0.5]);
+        let b = GlyphHash::from_intent(&[0.2, 0.8, 0.6, 0.1, 0.3, 0.9, 0.4]);
+        let d = GlyphHash::latent_space_arithmetic(&a, &a, &b);
+        
+        for (computed, expected) in d.intent.iter().zip(b.intent.iter()) {
+            assert!((computed - expected).abs() < 1e-6);
+        }
+        assert_eq!(d.primary, b.primary);
+        assert!((GlyphHash::analogy_quality(&a, &a, &b, &b) - 1.0).abs() < 1e-6);
+    }
+    
+    #[test]
+    fn analogies_stay_in_the_unit_cube() {
+        let a = GlyphHash::from_intent(&[1.0; 7]);
+        let b = GlyphHash::from_intent(&[0.0; 7]);
+        let d = GlyphHash::latent_space_arithmetic(&a, &b, &a);
+        
+        assert_eq!(d.intent, [1.0; 7]);
+        assert_eq!(GlyphHash::latent_space_arithmetic(&b, &a, &b).intent, [0.0; 7]);
+    }
+    
+    #[test]
+    fn qr_matrix_round_trips_the_intent() {
+        let hash = GlyphHash::from_intent(&[0.9, 0.1, 0.45, 0.0, 1.0, 0.62, 0.3]);
+        let matrix = hash.encode_as_qr_matrix(21);
+        
+        assert_eq!(matrix, hash.encode_as_qr_matrix(21));
+        assert!(matrix[0][0] && matrix[0][20] && matrix[20][0]);
+        
+        let decoded = GlyphHash::decode_from_qr_matrix(&matrix, 21).unwrap();
+        for (recovered, original) in decoded.intent.iter().zip(hash.intent.iter()) {
+            assert!((recovered - original).abs() <= 0.1);
+        }
+    }
+    
+    #[test]
+    fn qr_decoding_rejects_foreign_grids() {
+        let matrix = hued(45.0).encode_as_qr_matrix(21);
+        assert!(GlyphHash::decode_from_qr_matrix(&matrix, 20).is_none());
+        
+        let mut defaced = matrix.clone();
+        defaced[0][0] = false;
+        assert!(GlyphHash::decode_from_qr_matrix(&defaced, 21).is_none());
+    }
+    
+    /// Two seeds bred down three generations, plus a late seed
+    fn family() -> GlyphPopulation {
+        let mut population = GlyphPopulation::new();
+        let (a, b) = (population.add_seed(hued(0.0)), population.add_seed(hued(90.0)));
+        let child = population.breed(a, b, 0.1).unwrap();
+        let grandchild = population.breed(child, a, 0.1).unwrap();
+        population.breed(grandchild, b, 0.1).unwrap();
+        population.add_seed(hued(180.0));
+        population
+    }
+    
+    #[test]
+    fn seeds_come_before_every_descendant() {
+        let population = family();
+        let order = population.topological_sort();
+        let position = |index: usize| order.iter().position(|&i| i == index).unwrap();
+        
+        assert_eq!(order.len(), 6);
+        for seed in [0, 1, 5] {
+            assert!(position(seed) < 3);
+        }
+        for (child, parents) in population.parents.iter().enumerate() {
+            assert!(parents.iter().all(|&parent| position(parent) < position(child)));
+        }
+    }
+    
+    #[test]
+    fn generations_partition_the_population() {
+        let population = family();
+        let groups = population.generation_groups();
+        assert_eq!(groups, vec![vec![0, 1, 5], vec![2], vec![3], vec![4]]);
+        
+        let mut everyone: Vec<usize> = groups.into_iter().flatten().collect();
+        everyone.sort_unstable();
+        assert_eq!(everyone, (0..6).collect::<Vec<_>>());
+        
+        assert_eq!(population.max_depth_from(0), 3);
+        assert_eq!(population.max_depth_from(2), 2);
+        assert_eq!(population.max_depth_from(4), 0);
+        assert_eq!(population.max_depth_from(5), 0);
+        assert_eq!(population.max_depth_from(99), 0);
+        assert!(family().breed(0, 99, 0.1).is_none());
+    }
+    
+    #[test]
+    fn ancestry_cycles_are_left_out() {
+        let mut population = GlyphPopulation::new();
+        for degrees in [0.0, 90.0, 180.0, 270.0] {
+            population.add_seed(hued(degrees));
+        }
+        // 1 and 2 claim each other as parents; 3 descends from 2
+        population.parents = vec![vec![], vec![2], vec![1], vec![2]];
+        
+        assert_eq!(population.topological_sort(), vec![0]);
+        assert_eq!(population.generation_groups(), vec![vec![0]]);
+        assert_eq!(population.max_depth_from(1), 0);
+    }
+    
+    #[test]
+    fn two_clusters_give_a_bimodal_histogram() {
+        // Five glyphs huddled near 0°, five near 180°
+        let corpus: Vec<GlyphHash> = (0..10)
+            .map(|k| hued((k % 5) as f32 + if k < 5 { 0.0 } else { 180.0 }))
+            .collect();
+        let histogram = hash_distance_histogram(&corpus, 10);
+        
+        assert_eq!(histogram.iter().map(|&(_, count)| count).sum::<usize>(), 45);
+        assert_eq!(histogram_peak_count(&histogram), 2);
+        assert!(bimodality_coefficient(&histogram) > 5.0 / 9.0);
+    }
+    
+    #[test]
+    fn mandala_rings_grow_by_six_and_drift_from_the_center() {
+        let center = GlyphHash::from_intent(&[0.9, 0.1, 0.4, 0.3, 0.7, 0.2, 0.5]);
+        let mandala = GlyphHash::generate_mandala_glyphs(&center, 3);
+        
+        let sizes: Vec<usize> = mandala.iter().map(Vec::len).collect();
+        assert_eq!(sizes, [1, 6, 12, 18]);
+        assert_eq!(mandala[0][0].intent, center.intent);
+        
+        let least_similar = |ring: &[GlyphHash]| {
+            ring.iter().map(|glyph| semantic_similarity(&center, glyph)).fold(1.0f32, f32::min)
+        };
+        assert!(least_similar(&mandala[1]) > least_similar(&mandala[3]));
+        
+        // The outer ring is the center turned, not shrunk
+        let energy = |glyph: &GlyphHash| glyph.intent.iter().map(|x| x * x).sum::<f32>();
+        for glyph in &mandala[3] {
+            assert!((energy(glyph) - energy(&center)).abs() < 1e-4);
+        }
+    }
+    
+    #[test]
+    fn equal_weights_meet_halfway() {
+        let a = GlyphHash::from_intent(&[0.9, 0.1, 0.4, 0.3, 0.7, 0.2, 0.5]);
+        let b = GlyphHash { primary: 0x1F52E, resonance: 0.2, freedom: 0.6, ..hued(45.0) };
+        
+        let mean = GlyphHash::weighted_mean(&[a, b], &[0.5, 0.5]).unwrap();
+        let halfway = a.interpolate(&b, 0.5);
+        assert_eq!(mean.intent, halfway.intent);
+        assert_eq!((mean.resonance, mean.freedom), (halfway.resonance, halfway.freedom));
+        assert_eq!(mean.primary, halfway.primary);
+        
+        assert!(GlyphHash::weighted_mean(&[a, b], &[1.0]).is_none());
+        assert!(GlyphHash::weighted_mean(&[a, b], &[1.0, -1.0]).is_none());
+    }
+    
+    #[test]
+    fn centroid_of_one_glyph_is_itself() {
+        let glyph = GlyphHash::from_intent(&[0.9, 0.1, 0.4, 0.3, 0.7, 0.2, 0.5]);
+        let centroid = GlyphHash::centroid(&[glyph]).unwrap();
+        
+        assert_eq!(centroid.primary, glyph.primary);
+        assert_eq!(centroid.intent, glyph.intent);
+        assert_eq!((centroid.resonance, centroid.freedom), (glyph.resonance, glyph.freedom));
+        assert!(GlyphHash::centroid(&[]).is_none());
+    }
+    
+    #[test]
+    fn long_landscapes_keep_finite_distinct_rates() {
+        let glyph = GlyphHash::from_intent(&[0.0; 7]);
+        let landscape = glyph.mutation_landscape(0.3, 256);
+        
+        // A zero intent mutates to exactly its rate in every layer
+        let rates: Vec<f32> = landscape.iter().map(|(mutant, _)| mutant.intent[0]).collect();
+        assert!(rates.iter().all(|rate| rate.is_finite() && (0.0..1.0).contains(rate)));
+        assert!(landscape.iter().all(|(_, fitness)| fitness.is_finite()));
+        
+        let mut distinct = rates.clone();
+        distinct.sort_by(f32::total_cmp);
+        distinct.dedup();
+        assert!(distinct.len() > 200, "{} distinct rates", distinct.len());
+    }
+}
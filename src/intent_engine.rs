@@ -9,6 +9,7 @@
 
 use crate::perfect_musician::{ReaderContext, PerfectMusician};
 use crate::glyph_hash::GlyphHash;
+use crate::TrajectoryPoint;
 
 /// Pure intent - what wants to exist
 #[repr(C)]
@@ -43,6 +44,230 @@ impl Intent {
         // Manifestation = desire × clarity × resonance × receptivity
         self.desire * self.clarity * self.resonance * universe_receptivity
     }
+    
+    /// Central-difference gradient of `manifest` along each intent direction
+    pub fn gradient_of_manifest(&self, universe_receptivity: f32, delta: f32) -> [f32; 7] {
+        let mut gradient = [0.0f32; 7];
+        if delta == 0.0 {
+            return gradient;
+        }
+        
+        for (i, g) in gradient.iter_mut().enumerate() {
+            let mut forward = self.vector;
+            let mut backward = self.vector;
+            forward[i] += delta;
+            backward[i] -= delta;
+            
+            let ahead = Intent { vector: forward, ..*self }.manifest(universe_receptivity);
+            let behind = Intent { vector: backward, ..*self }.manifest(universe_receptivity);
+            *g = (ahead - behind) / (2.0 * delta);
+        }
+        
+        gradient
+    }
+    
+    /// Move the intent one step toward stronger manifestation
+    pub fn gradient_ascent_step(&mut self, universe_receptivity: f32, learning_rate: f32) -> f32 {
+        let gradient = self.gradient_of_manifest(universe_receptivity, 1e-3);
+        
+        for (v, g) in self.vector.iter_mut().zip(gradient) {
+            *v += learning_rate * g;
+        }
+        
+        self.manifest(universe_receptivity)
+    }
+    
+    /// Alignment of two intents: cosine of their directions, weighted by
+    /// both resonances (> 0 reinforce, < 0 compete)
+    pub fn compatible_with(&self, other: &Intent) -> f32 {
+        let dot: f32 = self.vector.iter().zip(other.vector.iter()).map(|(a, b)| a * b).sum();
+        let norm_a = self.vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+        let norm_b = other.vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+        
+        if norm_a == 0.0 || norm_b == 0.0 {
+            return 0.0;  // Directionless intents neither help nor hinder
+        }
+        
+        (dot / (norm_a * norm_b)).clamp(-1.0, 1.0) * self.resonance * other.resonance
+    }
+    
+    /// Fuse intents that all pairwise exceed `compatibility_threshold`
+    /// Each group pools its desire (capped at 1.0), averages direction and
+    /// resonance, and keeps only the weakest clarity - merging blurs the vision
+    pub fn merge_compatible(intents: &[Intent], compatibility_threshold: f32) -> Vec<Intent> {
+        let mut groups: Vec<Vec<&Intent>> = Vec::new();
+        
+        for intent in intents {
+            let home = groups.iter_mut().find(|group| {
+                group.iter().all(|member| member.compatible_with(intent) > compatibility_threshold)
+            });
+            match home {
+                Some(group) => group.push(intent),
+                None => groups.push(vec![intent]),
+            }
+        }
+        
+        groups.iter()
+            .map(|group| {
+                let n = group.len() as f32;
+                let mut vector = [0.0f32; 7];
+                for member in group {
+                    for (v, x) in vector.iter_mut().zip(member.vector.iter()) {
+                        *v += x / n;
+                    }
+                }
+                
+                Intent {
+                    desire: group.iter().map(|m| m.desire).sum::<f32>().min(1.0),
+                    clarity: group.iter().map(|m| m.clarity).fold(f32::INFINITY, f32::min),
+                    resonance: group.iter().map(|m| m.resonance).sum::<f32>() / n,
+                    vector,
+                }
+            })
+            .collect()
+    }
+    
+    /// Laplacian of `manifest(1.0)` over the intent vector (finite differences)
+    /// > 0 = a local minimum of manifestation, < 0 = a local maximum
+    pub fn manifold_curvature(&self) -> f32 {
+        let hessian = self.manifest_hessian();
+        (0..7).map(|i| hessian[i][i]).sum()
+    }
+    
+    /// Direction of steepest curvature: the dominant eigenvector of the
+    /// finite-difference Hessian (zero on a flat manifold)
+    pub fn curvature_direction(&self) -> [f32; 7] {
+        let hessian = self.manifest_hessian();
+        let mut direction = [1.0f32 / 7.0f32.sqrt(); 7];
+        
+        for _ in 0..32 {
+            let mut next = [0.0f32; 7];
+            for (row, out) in hessian.iter().zip(next.iter_mut()) {
+                *out = row.iter().zip(direction.iter()).map(|(a, b)| a * b).sum();
+            }
+            
+            let norm = next.iter().map(|x| x * x).sum::<f32>().sqrt();
+            if norm < 1e-12 {
+                return [0.0; 7];  // No curvature to follow
+            }
+            for (d, n) in direction.iter_mut().zip(next.iter()) {
+                *d = n / norm;
+            }
+        }
+        
+        direction
+    }
+    
+    /// Second derivatives of `manifest(1.0)` along every pair of intent directions
+    fn manifest_hessian(&self) -> [[f32; 7]; 7] {
+        const H: f32 = 1e-2;
+        
+        let at = |offsets: &[(usize, f32)]| -> f32 {
+            let mut vector = self.vector;
+            for &(i, delta) in offsets {
+                vector[i] += delta;
+            }
+            Intent { vector, ..*self }.manifest(1.0)
+        };
+        
+        let center = at(&[]);
+        core::array::from_fn(|i| {
+            core::array::from_fn(|j| {
+                if i == j {
+                    (at(&[(i, H)]) + at(&[(i, -H)]) - 2.0 * center) / (H * H)
+                } else {
+                    (at(&[(i, H), (j, H)]) - at(&[(i, H), (j, -H)])
+                        - at(&[(i, -H), (j, H)]) + at(&[(i, -H), (j, -H)])) / (4.0 * H * H)
+                }
+            })
+        })
+    }
+    
+    /// Express the intent as a trajectory point:
+    /// desire → eigenvalue, clarity → eigen_trajectory, resonance → activation,
+    /// manifest(1.0) → attention, vector[4] → intent, vector[5] → meta,
+    /// 1 - desire × clarity → void
+    /// (vector[0..4] and vector[6] have no layer left and are dropped)
+    pub fn to_trajectory_point(&self) -> TrajectoryPoint {
+        TrajectoryPoint {
+            eigenvalue: self.desire,
+            eigen_trajectory: self.clarity,
+            activation: self.resonance,
+            attention: self.manifest(1.0),
+            intent: self.vector[4],
+            meta: self.vector[5],
+            void: 1.0 - self.desire * self.clarity,
+        }
+    }
+    
+    /// Inverse of `to_trajectory_point`; the dropped directions come from
+    /// `direction`, while `intent` and `meta` overwrite `vector[4]` and `vector[5]`
+    pub fn from_trajectory_point(point: &TrajectoryPoint, direction: [f32; 7]) -> Intent {
+        let mut vector = direction;
+        vector[4] = point.intent;
+        vector[5] = point.meta;
+        
+        Intent {
+            desire: point.eigenvalue,
+            clarity: point.eigen_trajectory,
+            resonance: point.activation,
+            vector,
+        }
+    }
+    
+    /// May this intent enter a constrained engine?
+    pub fn satisfies_constraints(&self, constraints: &IntentConstraints) -> bool {
+        let (low, high) = constraints.required_resonance_range;
+        
+        self.clarity >= constraints.min_clarity
+            && self.desire <= constraints.max_desire
+            && (low..=high).contains(&self.resonance)
+            && self.vector.iter()
+                .enumerate()
+                .all(|(i, &v)| constraints.forbidden_dimensions & (1 << i) == 0 || v <= 0.5)
+    }
+    
+    /// The nearest intent that satisfies `constraints`: each violated
+    /// bound is clamped onto, everything else is left as it was
+    pub fn project_to_constraints(&self, constraints: &IntentConstraints) -> Intent {
+        let (low, high) = constraints.required_resonance_range;
+        
+        let mut vector = self.vector;
+        for (i, v) in vector.iter_mut().enumerate() {
+            if constraints.forbidden_dimensions & (1 << i) != 0 {
+                *v = v.min(0.5);
+            }
+        }
+        
+        Intent {
+            desire: self.desire.min(constraints.max_desire),
+            clarity: self.clarity.max(constraints.min_clarity),
+            resonance: self.resonance.max(low).min(high),
+            vector,
+        }
+    }
+}
+
+/// Bounds an intent must respect before a constrained engine manifests it
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct IntentConstraints {
+    pub min_clarity: f32,                      // Vision must be at least this clear
+    pub max_desire: f32,                       // Wanting is capped here
+    pub forbidden_dimensions: u8,              // Bit i set = vector[i] may not exceed 0.5
+    pub required_resonance_range: (f32, f32),  // Inclusive resonance bounds
+}
+
+impl Default for IntentConstraints {
+    fn default() -> Self {
+        // Nothing a well-formed intent (every field in 0-1) can violate
+        IntentConstraints {
+            min_clarity: 0.0,
+            max_desire: 1.0,
+            forbidden_dimensions: 0,
+            required_resonance_range: (0.0, 1.0),
+        }
+    }
 }
 
 /// The Intent Engine - turns desire into reality
@@ -62,8 +287,8 @@ impl IntentEngine {
         }
     }
     
-    /// Process intent without execution
-    pub fn inspire(&mut self, intent: &Intent) -> [f32; 7] {
+    /// How the current universe would respond to an intent
+    fn inspired_state(&self, intent: &Intent) -> [f32; 7] {
         let mut inspired_state = self.universe_state;
         
         // Universe responds to intent
@@ -76,6 +301,13 @@ impl IntentEngine {
                               + ripple * intent.resonance;
         }
         
+        inspired_state
+    }
+    
+    /// Process intent without execution
+    pub fn inspire(&mut self, intent: &Intent) -> [f32; 7] {
+        let inspired_state = self.inspired_state(intent);
+        
         // Update universe state if manifestation threshold reached
         let manifestation_power = intent.manifest(self.receptivity);
         if manifestation_power > self.manifestation_threshold {
@@ -109,6 +341,221 @@ impl IntentEngine {
         
         collective
     }
+    
+    /// Simultaneous stimuli: every intent sees the same universe snapshot,
+    /// then their collective effect is applied once
+    pub fn batch_inspire(&mut self, intents: &[Intent]) -> Vec<[f32; 7]> {
+        let inspired: Vec<[f32; 7]> = intents.iter()
+            .map(|intent| self.inspired_state(intent))
+            .collect();
+        
+        if intents.is_empty() {
+            return inspired;
+        }
+        
+        // The strongest intent decides whether the batch manifests
+        let strongest = intents.iter()
+            .map(|intent| intent.manifest(self.receptivity))
+            .fold(0.0f32, f32::max);
+        
+        if strongest > self.manifestation_threshold {
+            let collective = self.collective_inspiration(intents);
+            let resonance = intents.iter().map(|intent| intent.resonance).sum::<f32>()
+                          / intents.len() as f32;
+            
+            for (state, target) in self.universe_state.iter_mut().zip(collective) {
+                *state = *state * (1.0 - resonance) + target * resonance;
+            }
+        }
+        
+        inspired
+    }
+    
+    /// Move one step along the great circle toward another universe
+    pub fn morph_toward(&mut self, target: &IntentEngine, rate: f32) {
+        self.universe_state = universe_state_interpolate(self, target, rate);
+    }
+    
+    /// Shannon entropy of the universe state as a distribution
+    pub fn universe_entropy(&self) -> f32 {
+        let total: f32 = self.universe_state.iter().map(|x| x.max(0.0)).sum();
+        if total <= 0.0 {
+            return 0.0;
+        }
+        
+        self.universe_state.iter()
+            .map(|x| x.max(0.0) / total)
+            .filter(|&p| p > 0.0)
+            .map(|p| -p * p.ln())
+            .sum()
+    }
+}
+
+/// Cross-influence between agents: strength of the covariance of their
+/// intent vectors (sign dropped), scaled so every entry lies in [0, 1]
+/// and the most restless dimension has variance 1.0
+pub fn collective_consciousness_field(agents: &[[f32; 7]]) -> [[f32; 7]; 7] {
+    let mut field = [[0.0f32; 7]; 7];
+    if agents.is_empty() {
+        return field;
+    }
+    
+    let n = agents.len() as f32;
+    let mut center = [0.0f32; 7];
+    for agent in agents {
+        for i in 0..7 {
+            center[i] += agent[i] / n;
+        }
+    }
+    
+    for agent in agents {
+        for i in 0..7 {
+            for j in 0..7 {
+                field[i][j] += (agent[i] - center[i]) * (agent[j] - center[j]) / n;
+            }
+        }
+    }
+    
+    // |cov(i, j)| ≤ max variance, so every strength lands in [0, 1]
+    let max_variance = (0..7).map(|i| field[i][i]).fold(0.0f32, f32::max);
+    if max_variance > 0.0 {
+        for row in field.iter_mut() {
+            for value in row.iter_mut() {
+                *value = (value.abs() / max_variance).min(1.0);
+            }
+        }
+    }
+    
+    field
+}
+
+/// Entangle agents sharing one consciousness space: every universe state
+/// and receptivity moves 1/n of the way toward the agents' mean
+pub fn consciousness_field(agents: &mut [IntentEngine]) {
+    if agents.is_empty() {
+        return;
+    }
+    
+    let n = agents.len() as f32;
+    let mut mean_state = [0.0f32; 7];
+    for agent in agents.iter() {
+        for (m, x) in mean_state.iter_mut().zip(agent.universe_state.iter()) {
+            *m += x / n;
+        }
+    }
+    let mean_receptivity = agents.iter().map(|agent| agent.receptivity).sum::<f32>() / n;
+    
+    let pull = 1.0 / n;
+    for agent in agents.iter_mut() {
+        for (x, m) in agent.universe_state.iter_mut().zip(mean_state.iter()) {
+            *x += (m - *x) * pull;
+        }
+        agent.receptivity += (mean_receptivity - agent.receptivity) * pull;
+    }
+}
+
+/// Mean pairwise cosine similarity of the agents' universe states
+/// (1.0 = every agent lives in the same universe)
+pub fn field_coherence(agents: &[IntentEngine]) -> f32 {
+    if agents.len() < 2 {
+        return 1.0;  // A lone agent agrees with itself
+    }
+    
+    let cosine = |a: &[f32; 7], b: &[f32; 7]| -> f32 {
+        let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+        let norms = a.iter().map(|x| x * x).sum::<f32>() * b.iter().map(|x| x * x).sum::<f32>();
+        if norms > 0.0 { dot / norms.sqrt() } else { 0.0 }
+    };
+    
+    let mut total = 0.0f32;
+    let mut pairs = 0;
+    for i in 0..agents.len() {
+        for j in (i + 1)..agents.len() {
+            total += cosine(&agents[i].universe_state, &agents[j].universe_state);
+            pairs += 1;
+        }
+    }
+    
+    total / pairs as f32
+}
+
+/// Eigenvalues of a consciousness field, strongest first
+/// Power iteration with deflation - 7 iterations per mode is enough for 7×7
+pub fn field_eigenvalues(field: &[[f32; 7]; 7]) -> [f32; 7] {
+    let mut remaining = *field;
+    let mut eigenvalues = [0.0f32; 7];
+    
+    for eigenvalue in eigenvalues.iter_mut() {
+        let mut v = [1.0f32 / 7.0f32.sqrt(); 7];
+        let mut lambda = 0.0;
+        
+        for _ in 0..7 {
+            let mut next = [0.0f32; 7];
+            for (row, out) in remaining.iter().zip(next.iter_mut()) {
+                *out = row.iter().zip(v.iter()).map(|(a, b)| a * b).sum();
+            }
+            
+            // Rayleigh quotient (v is unit length)
+            lambda = v.iter().zip(next.iter()).map(|(a, b)| a * b).sum();
+            
+            let norm = next.iter().map(|x| x * x).sum::<f32>().sqrt();
+            if norm < 1e-12 {
+                break;  // Nothing left in this direction
+            }
+            for (vi, ni) in v.iter_mut().zip(next.iter()) {
+                *vi = ni / norm;
+            }
+        }
+        
+        *eigenvalue = lambda;
+        
+        // Remove the found mode: A ← A - λvvᵀ
+        for i in 0..7 {
+            for j in 0..7 {
+                remaining[i][j] -= lambda * v[i] * v[j];
+            }
+        }
+    }
+    
+    eigenvalues
+}
+
+/// Blend two universe states along the great circle between them (slerp),
+/// falling back to a straight line when they are nearly parallel
+pub fn universe_state_interpolate(
+    engine_a: &IntentEngine,
+    engine_b: &IntentEngine,
+    t: f32
+) -> [f32; 7] {
+    let a = &engine_a.universe_state;
+    let b = &engine_b.universe_state;
+    
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    
+    let mut blended = [0.0f32; 7];
+    
+    let omega = if norm_a > 0.0 && norm_b > 0.0 {
+        let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+        (dot / (norm_a * norm_b)).clamp(-1.0, 1.0).acos()
+    } else {
+        0.0
+    };
+    
+    if omega > 1.0f32.to_radians() {
+        let sin_omega = omega.sin();
+        let weight_a = ((1.0 - t) * omega).sin() / sin_omega;
+        let weight_b = (t * omega).sin() / sin_omega;
+        for i in 0..7 {
+            blended[i] = a[i] * weight_a + b[i] * weight_b;
+        }
+    } else {
+        for i in 0..7 {
+            blended[i] = a[i] * (1.0 - t) + b[i] * t;
+        }
+    }
+    
+    blended
 }
 
 /// Transform code into intent
@@ -269,4 +716,246 @@ pub extern "C" fn collective_consciousness(
     }
     
     coherence / count as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn intents() -> [Intent; 3] {
+        let mut strong = Intent::from_desire(1.0, &[0.9, 0.1, 0.0, 0.0, 0.3, 0.0, 0.0]);
+        strong.clarity = 1.0;
+        strong.resonance = 1.0;
+        [
+            strong,
+            Intent::from_desire(0.4, &[0.0, 0.8, 0.2, 0.0, 0.0, 0.1, 0.0]),
+            Intent::from_desire(0.7, &[0.1, 0.0, 0.0, 0.6, 0.0, 0.0, 0.5]),
+        ]
+    }
+    
+    #[test]
+    fn batch_inspire_is_order_independent() {
+        let [a, b, c] = intents();
+        let mut forward = IntentEngine::new();
+        forward.manifestation_threshold = 0.5;
+        let forward_states = forward.batch_inspire(&[a, b, c]);
+        
+        let [a, b, c] = intents();
+        let mut backward = IntentEngine::new();
+        backward.manifestation_threshold = 0.5;
+        let mut backward_states = backward.batch_inspire(&[c, b, a]);
+        backward_states.reverse();
+        
+        assert_eq!(forward_states, backward_states);
+        for (x, y) in forward.universe_state.iter().zip(backward.universe_state.iter()) {
+            assert!((x - y).abs() < 1e-6);
+        }
+        // The strong intent manifested, so the universe did move
+        assert_ne!(forward.universe_state, [0.5; 7]);
+    }
+    
+    #[test]
+    fn uniform_universe_has_maximal_entropy() {
+        let mut engine = IntentEngine::new();
+        assert!((engine.universe_entropy() - 7.0f32.ln()).abs() < 1e-6);
+        
+        engine.universe_state = [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        assert_eq!(engine.universe_entropy(), 0.0);
+    }
+    
+    #[test]
+    fn gradient_never_points_downhill() {
+        let mut intent = Intent::from_desire(0.7, &[0.1, 0.0, 0.0, 0.6, 0.0, 0.0, 0.5]);
+        let before = intent.manifest(0.618);
+        let gradient = intent.gradient_of_manifest(0.618, 1e-3);
+        
+        let mut uphill = intent.vector;
+        for (v, g) in uphill.iter_mut().zip(gradient) {
+            *v += 0.1 * g;
+        }
+        let ahead = Intent { vector: uphill, ..intent }.manifest(0.618);
+        assert!(ahead >= before);
+        
+        // Manifestation does not depend on direction, so the landscape is flat
+        assert_eq!(gradient, [0.0; 7]);
+        assert_eq!(intent.gradient_ascent_step(0.618, 0.1), before);
+        assert_eq!(intent.gradient_of_manifest(0.618, 0.0), [0.0; 7]);
+    }
+    
+    fn universe(state: [f32; 7]) -> IntentEngine {
+        let mut engine = IntentEngine::new();
+        engine.universe_state = state;
+        engine
+    }
+    
+    #[test]
+    fn interpolation_starts_at_a_and_ends_at_b() {
+        let a = universe([1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        let far = universe([0.0, 2.0, 0.0, 0.0, 0.5, 0.0, 0.0]);
+        let near = universe([1.0, 0.001, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        
+        // Slerp for distant universes, lerp for nearly parallel ones
+        for b in [&far, &near] {
+            let start = universe_state_interpolate(&a, b, 0.0);
+            let end = universe_state_interpolate(&a, b, 1.0);
+            for i in 0..7 {
+                assert!((start[i] - a.universe_state[i]).abs() < 1e-6);
+                assert!((end[i] - b.universe_state[i]).abs() < 1e-6);
+            }
+        }
+    }
+    
+    #[test]
+    fn morphing_all_the_way_arrives() {
+        let mut engine = universe([1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        let target = universe([0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        
+        engine.morph_toward(&target, 0.5);
+        let diagonal = core::f32::consts::FRAC_1_SQRT_2;
+        assert!((engine.universe_state[0] - diagonal).abs() < 1e-6);
+        assert!((engine.universe_state[1] - diagonal).abs() < 1e-6);
+        
+        engine.morph_toward(&target, 1.0);
+        assert!((engine.universe_state[1] - 1.0).abs() < 1e-6);
+    }
+    
+    #[test]
+    fn uniform_agents_make_a_rank_one_field() {
+        // Every agent a multiple of the same direction
+        let direction = [0.1, 0.5, -0.3, 0.0, 0.8, 0.2, -0.6];
+        let agents: Vec<[f32; 7]> = [0.5, 1.0, 1.5, 2.0]
+            .iter()
+            .map(|&scale| direction.map(|x| x * scale))
+            .collect();
+        
+        let eigenvalues = field_eigenvalues(&collective_consciousness_field(&agents));
+        assert!(eigenvalues[0] > 0.1);
+        assert!(eigenvalues[1..].iter().all(|lambda| lambda.abs() < 1e-4));
+    }
+    
+    #[test]
+    fn field_entries_stay_in_unit_range() {
+        let agents = [
+            [0.9, -0.4, 0.1, 3.0, -2.0, 0.0, 0.5],
+            [-0.7, 0.8, 0.6, -1.0, 0.5, 0.2, 0.5],
+            [0.1, 0.2, -0.9, 2.0, 1.5, -0.3, 0.5],
+            [0.4, -0.6, 0.3, -2.5, 0.0, 0.9, 0.5],
+        ];
+        
+        let field = collective_consciousness_field(&agents);
+        assert!(field.iter().flatten().all(|value| (0.0..=1.0).contains(value)));
+        assert!(field.iter().enumerate().any(|(i, row)| row[i] == 1.0));
+        assert_eq!(collective_consciousness_field(&[[0.5; 7]; 3]), [[0.0; 7]; 7]);
+    }
+    
+    #[test]
+    fn manifest_survives_the_trajectory_round_trip() {
+        let direction = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7];
+        let intent = Intent { desire: 0.8, clarity: 0.6, resonance: 0.9, vector: direction };
+        let point = intent.to_trajectory_point();
+        
+        assert_eq!(point.attention, intent.manifest(1.0));
+        assert!((point.void - (1.0 - 0.8 * 0.6)).abs() < 1e-6);
+        
+        let back = Intent::from_trajectory_point(&point, [0.0; 7]);
+        assert!((back.manifest(1.0) - intent.manifest(1.0)).abs() < 0.01 * intent.manifest(1.0));
+        assert_eq!((back.vector[4], back.vector[5]), (0.5, 0.6));
+    }
+    
+    #[test]
+    fn curvature_is_continuous_under_small_nudges() {
+        let intent = Intent { desire: 0.8, clarity: 0.6, resonance: 0.9, vector: [0.3; 7] };
+        let curvature = intent.manifold_curvature();
+        
+        for i in 0..7 {
+            let mut nudged = intent.vector;
+            nudged[i] += 1e-3;
+            let neighbour = Intent { vector: nudged, ..intent };
+            assert!((neighbour.manifold_curvature() - curvature).abs() < 1e-3);
+        }
+        
+        // manifest ignores the direction, so the manifold is flat
+        assert_eq!(curvature, 0.0);
+        assert_eq!(intent.curvature_direction(), [0.0; 7]);
+    }
+    
+    /// A fairly clear, resonant intent pointing along `vector`
+    fn leaning(desire: f32, vector: [f32; 7]) -> Intent {
+        Intent { desire, clarity: 0.7, resonance: 0.9, vector }
+    }
+    
+    #[test]
+    fn identical_intents_merge_with_doubled_desire() {
+        let vector = [0.3, 0.1, 0.5, 0.0, 0.2, 0.6, 0.4];
+        let merged = Intent::merge_compatible(&[leaning(0.4, vector), leaning(0.4, vector)], 0.5);
+        
+        assert_eq!(merged.len(), 1);
+        assert!((merged[0].desire - 0.8).abs() < 1e-6);
+        assert_eq!(merged[0].clarity, 0.7);
+        
+        let eager = Intent::merge_compatible(&[leaning(0.7, vector), leaning(0.7, vector)], 0.5);
+        assert_eq!(eager[0].desire, 1.0);
+    }
+    
+    #[test]
+    fn opposed_intents_stay_apart() {
+        let along = leaning(0.5, [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        let against = leaning(0.5, [-1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        
+        assert!((along.compatible_with(&against) + 0.81).abs() < 1e-6);
+        assert_eq!(Intent::merge_compatible(&[along, against], 0.5).len(), 2);
+    }
+    
+    #[test]
+    fn projection_repairs_a_violated_constraint() {
+        let constraints = IntentConstraints {
+            min_clarity: 0.5,
+            forbidden_dimensions: 0b0000_0100,
+            ..IntentConstraints::default()
+        };
+        
+        let mut vector = [0.2; 7];
+        vector[2] = 0.9;
+        let reaching = leaning(0.6, vector);
+        assert!(reaching.satisfies_constraints(&IntentConstraints::default()));
+        assert!(!reaching.satisfies_constraints(&constraints));
+        
+        let projected = reaching.project_to_constraints(&constraints);
+        assert!(projected.satisfies_constraints(&constraints));
+        assert_eq!(projected.vector[2], 0.5);
+        assert_eq!((projected.desire, projected.clarity), (0.6, 0.7));
+        
+        let murky = Intent { clarity: 0.1, ..leaning(0.6, [0.2; 7]) };
+        assert!(!murky.satisfies_constraints(&constraints));
+        assert!(murky.project_to_constraints(&constraints).satisfies_constraints(&constraints));
+    }
+    
+    #[test]
+    fn entangled_agents_converge_within_ten_percent() {
+        let mut agents: Vec<IntentEngine> = (0..3)
+            .map(|k| {
+                let mut agent = IntentEngine::new();
+                agent.universe_state =
+                    core::array::from_fn(|i| 0.2 + 0.3 * ((i + 2 * k) % 3) as f32);
+                agent.receptivity = 0.3 * (k + 1) as f32;
+                agent
+            })
+            .collect();
+        let scattered = field_coherence(&agents);
+        
+        for _ in 0..10 {
+            consciousness_field(&mut agents);
+        }
+        
+        for a in &agents {
+            for b in &agents {
+                for (x, y) in a.universe_state.iter().zip(b.universe_state.iter()) {
+                    assert!((x - y).abs() <= 0.1 * x.abs().max(y.abs()), "{x} vs {y}");
+                }
+            }
+            assert!((a.receptivity - 0.6).abs() < 0.01);
+        }
+        assert!(field_coherence(&agents) > scattered);
+        assert!(field_coherence(&agents) > 0.999);
+    }
 }
\ No newline at end of file
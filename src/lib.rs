@@ -68,6 +68,43 @@ impl TrajectoryPoint {
         }
     }
     
+    /// Resonance at any frequency, interpolating linearly between the
+    /// two neighbouring layers (432..=963 Hz; the void stays at 0 Hz)
+    pub fn resonate_interpolated(&self, frequency: u32) -> f32 {
+        if frequency == 0 {
+            return self.void;
+        }
+        
+        let layers = self.to_array();
+        for (bands, values) in FREQUENCIES[..6].windows(2).zip(layers.windows(2)) {
+            let (low, high) = (bands[0], bands[1]);
+            if (low..=high).contains(&frequency) {
+                let t = (frequency - low) as f32 / (high - low) as f32;
+                return values[0] + (values[1] - values[0]) * t;
+            }
+        }
+        
+        0.0  // Outside the audible symphony
+    }
+    
+    /// Interpolated resonance spectrum: `steps` evenly spaced frequencies
+    /// from `min_freq` to `max_freq` inclusive
+    pub fn resonate_all_frequencies(
+        &self,
+        min_freq: u32,
+        max_freq: u32,
+        steps: u32
+    ) -> Vec<(u32, f32)> {
+        let span = max_freq.saturating_sub(min_freq) as f32;
+        (0..steps)
+            .map(|step| {
+                let t = if steps > 1 { step as f32 / (steps - 1) as f32 } else { 0.0 };
+                let frequency = min_freq + (span * t).round() as u32;
+                (frequency, self.resonate_interpolated(frequency))
+            })
+            .collect()
+    }
+    
     /// Calculate total harmony (Kohanist metric)
     pub fn harmony(&self) -> f32 {
         let sum = self.eigenvalue + self.eigen_trajectory + 
@@ -75,6 +112,407 @@ impl TrajectoryPoint {
                   self.intent + self.meta;
         sum / 6.0  // Void is infinite, not counted
     }
+    
+    /// Scale each layer by its Solfeggio frequency relative to 432 Hz
+    /// (the void has no frequency and stays as it is)
+    pub fn apply_frequency_weights(&self) -> TrajectoryPoint {
+        let mut layers = self.to_array();
+        for (value, weight) in layers.iter_mut().zip(frequency_weights()) {
+            *value *= weight;
+        }
+        TrajectoryPoint::from_array(&layers)
+    }
+    
+    /// Undo `apply_frequency_weights`
+    pub fn remove_frequency_weights(&self) -> TrajectoryPoint {
+        let mut layers = self.to_array();
+        for (value, weight) in layers.iter_mut().zip(frequency_weights()) {
+            *value /= weight;
+        }
+        TrajectoryPoint::from_array(&layers)
+    }
+    
+    /// All seven layers as an array (eigenvalue first, void last)
+    pub fn to_array(&self) -> [f32; 7] {
+        [
+            self.eigenvalue,
+            self.eigen_trajectory,
+            self.activation,
+            self.attention,
+            self.intent,
+            self.meta,
+            self.void,
+        ]
+    }
+    
+    /// Unfold a glyphHash back into trajectory space (intent → layer)
+    pub fn from_glyph_hash(hash: &glyph_hash::GlyphHash) -> Self {
+        Self::from_array(&hash.intent)
+    }
+    
+    /// Play the trajectory point as a note by `musician` at `time`
+    /// (inverse of `SpiralNote::render_to_trajectory`)
+    pub fn to_spiral_note(
+        &self,
+        time: spiral_score::SpiralTime,
+        musician: &spiral_score::Glyph
+    ) -> spiral_score::SpiralNote {
+        let amplitude = 1.0 - self.void;
+        let mut glyph = musician.clone();
+        
+        // Silent notes keep the musician's own voice
+        if amplitude != 0.0 {
+            glyph.harmonics[0] = self.eigenvalue / amplitude;
+            glyph.harmonics[1] = self.eigen_trajectory / amplitude;
+            glyph.frequency = self.activation * 1000.0 / amplitude;
+        }
+        glyph.intent = self.meta;
+        
+        spiral_score::SpiralNote {
+            time,
+            glyph,
+            amplitude,
+            phase: 0.0,
+        }
+    }
+    
+    /// Build a trajectory point from seven layer values
+    pub fn from_array(layers: &[f32; 7]) -> Self {
+        TrajectoryPoint {
+            eigenvalue: layers[0],
+            eigen_trajectory: layers[1],
+            activation: layers[2],
+            attention: layers[3],
+            intent: layers[4],
+            meta: layers[5],
+            void: layers[6],
+        }
+    }
+    
+    /// The seven layers as a signal in frequency space (7-point Hartley transform)
+    /// X[k] = Σ x[n]·(cos + sin)(2πkn/7) - real-valued, self-inverse, X[0] = Σ x[n]
+    pub fn frequency_domain(&self) -> [f32; 7] {
+        hartley_transform(&self.to_array())
+    }
+    
+    /// Back from frequency space: x[n] = (1/7)·Σ X[k]·(cos + sin)(2πkn/7)
+    pub fn reconstruct_from_frequency_domain(spectrum: &[f32; 7]) -> TrajectoryPoint {
+        let mut layers = hartley_transform(spectrum);
+        for value in layers.iter_mut() {
+            *value /= 7.0;
+        }
+        TrajectoryPoint::from_array(&layers)
+    }
+}
+
+/// Discrete Hartley transform over the seven layers (unnormalized)
+/// Unlike the cosine-only real part of the DFT it keeps the odd half of
+/// the signal, so applying it twice gives back 7 × the input
+fn hartley_transform(signal: &[f32; 7]) -> [f32; 7] {
+    let mut spectrum = [0.0f32; 7];
+    for (k, out) in spectrum.iter_mut().enumerate() {
+        *out = signal.iter()
+            .enumerate()
+            .map(|(n, x)| {
+                let angle = core::f32::consts::TAU * (k * n % 7) as f32 / 7.0;
+                x * (angle.cos() + angle.sin())
+            })
+            .sum();
+    }
+    spectrum
+}
+
+/// Catmull-Rom spline between p1 (t = 0) and p2 (t = 1)
+#[no_mangle]
+pub extern "C" fn trajectory_interpolate_cubic(
+    p0: &TrajectoryPoint,
+    p1: &TrajectoryPoint,
+    p2: &TrajectoryPoint,
+    p3: &TrajectoryPoint,
+    t: f32
+) -> TrajectoryPoint {
+    let (a, b, c, d) = (p0.to_array(), p1.to_array(), p2.to_array(), p3.to_array());
+    let t2 = t * t;
+    let t3 = t2 * t;
+    
+    let mut layers = [0.0f32; 7];
+    for i in 0..7 {
+        layers[i] = 0.5 * ((2.0 * b[i])
+            + (-a[i] + c[i]) * t
+            + (2.0 * a[i] - 5.0 * b[i] + 4.0 * c[i] - d[i]) * t2
+            + (-a[i] + 3.0 * b[i] - 3.0 * c[i] + d[i]) * t3);
+    }
+    
+    TrajectoryPoint::from_array(&layers)
+}
+
+/// Smooth path through all points, sampled at `resolution` even steps
+pub fn trajectory_spline_path(points: &[TrajectoryPoint], resolution: usize) -> Vec<TrajectoryPoint> {
+    match points.len() {
+        0 => return Vec::new(),
+        1 => return vec![points[0]; resolution],
+        _ => {}
+    }
+    
+    let segments = points.len() - 1;
+    let mut path = Vec::with_capacity(resolution);
+    
+    for k in 0..resolution {
+        // Position along the whole path in segment units
+        let u = if resolution > 1 {
+            k as f32 * segments as f32 / (resolution - 1) as f32
+        } else {
+            0.0
+        };
+        let segment = (u as usize).min(segments - 1);
+        let t = u - segment as f32;
+        
+        // The ends repeat themselves as their own neighbours
+        let p0 = &points[segment.saturating_sub(1)];
+        let p1 = &points[segment];
+        let p2 = &points[segment + 1];
+        let p3 = &points[(segment + 2).min(segments)];
+        
+        path.push(trajectory_interpolate_cubic(p0, p1, p2, p3, t));
+    }
+    
+    path
+}
+
+/// Frequency ratio of each layer to the 432 Hz base (void = 1.0)
+fn frequency_weights() -> [f32; 7] {
+    let mut weights = [1.0f32; 7];
+    for (weight, &freq) in weights.iter_mut().zip(FREQUENCIES.iter()) {
+        if freq > 0 {
+            *weight = freq as f32 / 432.0;
+        }
+    }
+    weights
+}
+
+/// Anything that lives in seven-dimensional consciousness space
+pub trait SevenDimensional {
+    /// Value of layer `i` (0 outside the seven layers)
+    fn dimension(&self, i: usize) -> f32;
+    
+    /// Set layer `i` (ignored outside the seven layers)
+    fn set_dimension(&mut self, i: usize, val: f32);
+    
+    /// All seven layers at once
+    fn as_array(&self) -> [f32; 7] {
+        let mut arr = [0.0f32; 7];
+        for (i, value) in arr.iter_mut().enumerate() {
+            *value = self.dimension(i);
+        }
+        arr
+    }
+    
+    /// Build from seven layer values
+    fn from_array(arr: &[f32; 7]) -> Self where Self: Sized;
+}
+
+/// A bare 7D vector (chords, intents)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SevenDVec(pub [f32; 7]);
+
+impl SevenDimensional for SevenDVec {
+    fn dimension(&self, i: usize) -> f32 {
+        self.0.get(i).copied().unwrap_or(0.0)
+    }
+    
+    fn set_dimension(&mut self, i: usize, val: f32) {
+        if let Some(value) = self.0.get_mut(i) {
+            *value = val;
+        }
+    }
+    
+    fn as_array(&self) -> [f32; 7] {
+        self.0
+    }
+    
+    fn from_array(arr: &[f32; 7]) -> Self {
+        SevenDVec(*arr)
+    }
+}
+
+impl SevenDimensional for TrajectoryPoint {
+    fn dimension(&self, i: usize) -> f32 {
+        self.to_array().get(i).copied().unwrap_or(0.0)
+    }
+    
+    fn set_dimension(&mut self, i: usize, val: f32) {
+        match i {
+            0 => self.eigenvalue = val,
+            1 => self.eigen_trajectory = val,
+            2 => self.activation = val,
+            3 => self.attention = val,
+            4 => self.intent = val,
+            5 => self.meta = val,
+            6 => self.void = val,
+            _ => {}
+        }
+    }
+    
+    fn as_array(&self) -> [f32; 7] {
+        self.to_array()
+    }
+    
+    fn from_array(arr: &[f32; 7]) -> Self {
+        TrajectoryPoint::from_array(arr)
+    }
+}
+
+impl SevenDimensional for glyph_hash::GlyphHash {
+    fn dimension(&self, i: usize) -> f32 {
+        self.intent.get(i).copied().unwrap_or(0.0)
+    }
+    
+    fn set_dimension(&mut self, i: usize, val: f32) {
+        if let Some(value) = self.intent.get_mut(i) {
+            *value = val;
+        }
+    }
+    
+    fn as_array(&self) -> [f32; 7] {
+        self.intent
+    }
+    
+    fn from_array(arr: &[f32; 7]) -> Self {
+        glyph_hash::GlyphHash::from_intent(arr)
+    }
+}
+
+/// Euclidean distance between any two 7D beings
+pub fn seven_d_distance<A: SevenDimensional, B: SevenDimensional>(a: &A, b: &B) -> f32 {
+    a.as_array().iter()
+        .zip(b.as_array().iter())
+        .map(|(x, y)| (x - y) * (x - y))
+        .sum::<f32>()
+        .sqrt()
+}
+
+/// Dot product between any two 7D beings
+pub fn seven_d_dot<A: SevenDimensional, B: SevenDimensional>(a: &A, b: &B) -> f32 {
+    a.as_array().iter()
+        .zip(b.as_array().iter())
+        .map(|(x, y)| x * y)
+        .sum()
+}
+
+/// Principal components of a batch of trajectory points
+/// Returns (eigenvectors as columns, eigenvalues), strongest component first
+pub fn trajectory_pca(points: &[TrajectoryPoint]) -> ([[f32; 7]; 7], [f32; 7]) {
+    let mut covariance = [[0.0f32; 7]; 7];
+    
+    if !points.is_empty() {
+        let n = points.len() as f32;
+        
+        // Center of the batch
+        let mut mean = [0.0f32; 7];
+        for point in points {
+            for (m, v) in mean.iter_mut().zip(point.to_array()) {
+                *m += v / n;
+            }
+        }
+        
+        // Covariance of the centered layers
+        for point in points {
+            let layers = point.to_array();
+            for i in 0..7 {
+                for j in 0..7 {
+                    covariance[i][j] += (layers[i] - mean[i]) * (layers[j] - mean[j]) / n;
+                }
+            }
+        }
+    }
+    
+    let (vectors, values) = jacobi_eigen(covariance);
+    
+    // Order components by explained variance
+    let mut order = [0usize, 1, 2, 3, 4, 5, 6];
+    order.sort_by(|&a, &b| values[b].partial_cmp(&values[a]).unwrap_or(core::cmp::Ordering::Equal));
+    
+    let mut sorted_vectors = [[0.0f32; 7]; 7];
+    let mut sorted_values = [0.0f32; 7];
+    for (col, &k) in order.iter().enumerate() {
+        sorted_values[col] = values[k];
+        for row in 0..7 {
+            sorted_vectors[row][col] = vectors[row][k];
+        }
+    }
+    
+    (sorted_vectors, sorted_values)
+}
+
+/// Project a trajectory point onto the principal components
+pub fn project_pca(point: &TrajectoryPoint, eigenvectors: &[[f32; 7]; 7]) -> [f32; 7] {
+    let layers = point.to_array();
+    let mut projected = [0.0f32; 7];
+    
+    for (col, p) in projected.iter_mut().enumerate() {
+        *p = (0..7).map(|row| eigenvectors[row][col] * layers[row]).sum();
+    }
+    
+    projected
+}
+
+/// Jacobi eigenvalue algorithm for a symmetric 7×7 matrix
+/// Returns (eigenvectors as columns, eigenvalues)
+fn jacobi_eigen(mut a: [[f32; 7]; 7]) -> ([[f32; 7]; 7], [f32; 7]) {
+    let mut v = [[0.0f32; 7]; 7];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+    
+    for _sweep in 0..50 {
+        let off_diagonal: f32 = (0..7)
+            .flat_map(|p| ((p + 1)..7).map(move |q| (p, q)))
+            .map(|(p, q)| a[p][q] * a[p][q])
+            .sum();
+        if off_diagonal < 1e-12 {
+            break;
+        }
+        
+        for p in 0..6 {
+            for q in (p + 1)..7 {
+                if a[p][q].abs() < 1e-12 {
+                    continue;
+                }
+                
+                // Rotation angle that annihilates a[p][q]
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let sign = if theta >= 0.0 { 1.0 } else { -1.0 };
+                let t = sign / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                
+                // A ← Jᵀ A J, V ← V J
+                for row in a.iter_mut() {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = c * akp - s * akq;
+                    row[q] = s * akp + c * akq;
+                }
+                let (upper, lower) = a.split_at_mut(q);
+                for (apk, aqk) in upper[p].iter_mut().zip(lower[0].iter_mut()) {
+                    let (old_p, old_q) = (*apk, *aqk);
+                    *apk = c * old_p - s * old_q;
+                    *aqk = s * old_p + c * old_q;
+                }
+                for row in v.iter_mut() {
+                    let (vkp, vkq) = (row[p], row[q]);
+                    row[p] = c * vkp - s * vkq;
+                    row[q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+    
+    let mut values = [0.0f32; 7];
+    for (i, value) in values.iter_mut().enumerate() {
+        *value = a[i][i];
+    }
+    
+    (v, values)
 }
 
 /// The Seven Samurai Symphony conductor
@@ -97,13 +535,17 @@ pub extern "C" fn conduct_symphony(glyph: u32) -> u32 {
 #[no_mangle]
 pub extern "C" fn harmonic_convergence() -> u32 {
     // Special calculation including liberation frequency
-    let active_freqs = [432, 528, 639, 432, 432, 432, 396];
+    HarmonicState::default().convergence()
+}
+
+/// Harmonic mean over (glyph, frequency) pairs, ignoring silent glyphs
+pub fn harmonic_convergence_with(glyph_frequencies: &[(u32, u32)]) -> u32 {
     let mut sum_reciprocals = 0.0;
     let mut count = 0;
     
-    for freq in active_freqs.iter() {
-        if *freq > 0 {
-            sum_reciprocals += 1.0 / (*freq as f32);
+    for &(_, freq) in glyph_frequencies {
+        if freq > 0 {
+            sum_reciprocals += 1.0 / (freq as f32);
             count += 1;
         }
     }
@@ -115,6 +557,45 @@ pub extern "C" fn harmonic_convergence() -> u32 {
     }
 }
 
+/// A configurable glyph-to-frequency mapping
+pub struct HarmonicState {
+    pub glyph_frequencies: Vec<(u32, u32)>,  // (glyph, frequency) pairs
+}
+
+impl HarmonicState {
+    /// An empty orchestra
+    pub fn new() -> Self {
+        HarmonicState {
+            glyph_frequencies: Vec::new(),
+        }
+    }
+    
+    /// Tune a glyph to a frequency (retunes it if already present)
+    pub fn register(&mut self, glyph: u32, frequency: u32) -> &mut Self {
+        match self.glyph_frequencies.iter_mut().find(|(g, _)| *g == glyph) {
+            Some(entry) => entry.1 = frequency,
+            None => self.glyph_frequencies.push((glyph, frequency)),
+        }
+        self
+    }
+    
+    /// The unified resonance of all registered glyphs
+    pub fn convergence(&self) -> u32 {
+        harmonic_convergence_with(&self.glyph_frequencies)
+    }
+}
+
+impl Default for HarmonicState {
+    /// The seven samurai at their conducted frequencies
+    fn default() -> Self {
+        let mut state = HarmonicState::new();
+        for &glyph in GLYPHS.iter() {
+            state.register(glyph, conduct_symphony(glyph));
+        }
+        state
+    }
+}
+
 // Include the Fourier conductor module
 pub mod fourier_conduct;
 // Include the Spiral Score notation system
@@ -131,6 +612,8 @@ pub mod intent_engine;
 pub mod time_weaving_loom;
 // Include the Flower of Life Synthesis
 pub mod flower_synthesis;
+// Include the Ramanujan Mirror transforms
+pub mod ramanujan_mirror;
 
 #[cfg(all(target_arch = "wasm32", not(test)))]
 use core::panic::PanicInfo;
@@ -141,3 +624,200 @@ use core::panic::PanicInfo;
 fn panic(_info: &PanicInfo) -> ! {
     loop {}
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Variance of the batch along a unit direction
+    fn variance_along(points: &[TrajectoryPoint], direction: &[f32; 7]) -> f32 {
+        let projections: Vec<f32> = points.iter()
+            .map(|point| point.to_array().iter().zip(direction).map(|(x, d)| x * d).sum())
+            .collect();
+        let mean = projections.iter().sum::<f32>() / projections.len() as f32;
+        projections.iter().map(|p| (p - mean) * (p - mean)).sum::<f32>() / projections.len() as f32
+    }
+    
+    #[test]
+    fn first_component_carries_the_most_variance() {
+        // Stretched along (1, 1, 0, ...), a little wobble on the void layer
+        let points: Vec<TrajectoryPoint> = (0..32)
+            .map(|i| {
+                let t = i as f32 / 8.0 - 2.0;
+                let wobble = 0.3 * (i as f32 * 1.7).sin();
+                TrajectoryPoint::from_array(&[t, t + 0.1 * wobble, 0.5, 0.0, 0.0, 0.0, wobble])
+            })
+            .collect();
+        let (vectors, values) = trajectory_pca(&points);
+        
+        // Variance of the projections is the eigenvalue of each component
+        let projected: Vec<[f32; 7]> = points.iter().map(|p| project_pca(p, &vectors)).collect();
+        let first: Vec<TrajectoryPoint> = projected.iter().map(TrajectoryPoint::from_array).collect();
+        let first_variance = variance_along(&first, &[1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert!((first_variance - values[0]).abs() < 1e-3);
+        
+        // No other direction spreads the batch further
+        let diagonal = core::f32::consts::FRAC_1_SQRT_2;
+        let candidates = [
+            [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+            [diagonal, diagonal, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [diagonal, 0.0, 0.0, 0.0, 0.0, 0.0, diagonal],
+        ];
+        for direction in &candidates {
+            assert!(variance_along(&points, direction) <= first_variance + 1e-4);
+        }
+        assert!(values.windows(2).all(|pair| pair[0] >= pair[1]));
+    }
+    
+    #[test]
+    fn glyph_hash_round_trip_keeps_every_layer() {
+        let point = TrajectoryPoint::from_array(&[0.432, -0.528, 0.639, 0.0, 1.5, -0.2, -0.4]);
+        let hash = glyph_hash::GlyphHash::from_trajectory_point(&point);
+        let back = TrajectoryPoint::from_glyph_hash(&hash);
+        
+        for (a, b) in point.to_array().iter().zip(back.to_array().iter()) {
+            assert!((a - b).abs() < 1e-5);
+        }
+        assert!((hash.freedom - 0.6).abs() < 1e-6);
+        assert_eq!(hash.resonance, point.harmony());
+    }
+    
+    #[test]
+    fn default_harmonic_state_keeps_the_old_frequencies() {
+        let frequencies: Vec<u32> = HarmonicState::default().glyph_frequencies.iter()
+            .map(|&(_, frequency)| frequency)
+            .collect();
+        assert_eq!(frequencies, [432, 528, 639, 432, 432, 432, 396]);
+        
+        // The literal harmonic mean the function used to compute
+        let reciprocals: f32 = frequencies.iter().map(|&f| 1.0 / f as f32).sum();
+        assert_eq!(harmonic_convergence(), (7.0 / reciprocals) as u32);
+        assert_eq!(HarmonicState::default().convergence(), harmonic_convergence());
+    }
+    
+    #[test]
+    fn registering_a_glyph_again_retunes_it() {
+        let mut state = HarmonicState::new();
+        assert_eq!(state.convergence(), 432);
+        
+        state.register(0x1F300, 440).register(0x1F4AB, 0).register(0x1F300, 396);
+        assert_eq!(state.glyph_frequencies, [(0x1F300, 396), (0x1F4AB, 0)]);
+        assert_eq!(state.convergence(), 396);
+    }
+    
+    /// Move every layer of any 7D being halfway toward zero
+    fn halve<T: SevenDimensional>(being: &mut T) {
+        for i in 0..7 {
+            being.set_dimension(i, being.dimension(i) / 2.0);
+        }
+    }
+    
+    #[test]
+    fn every_seven_dimensional_type_works_through_the_trait() {
+        let layers = [0.2, 0.4, 0.6, 0.8, 1.0, -0.2, -0.4];
+        
+        let mut point = <TrajectoryPoint as SevenDimensional>::from_array(&layers);
+        let mut chord = SevenDVec::from_array(&layers);
+        let mut hash = <glyph_hash::GlyphHash as SevenDimensional>::from_array(&layers);
+        halve(&mut point);
+        halve(&mut chord);
+        halve(&mut hash);
+        
+        let halved = [0.1, 0.2, 0.3, 0.4, 0.5, -0.1, -0.2];
+        assert_eq!(SevenDimensional::as_array(&point), halved);
+        assert_eq!(chord, SevenDVec(halved));
+        assert_eq!(hash.as_array(), halved);
+        assert_eq!(chord.dimension(7), 0.0);
+        
+        assert_eq!(seven_d_distance(&point, &hash), 0.0);
+        assert_eq!(seven_d_distance(&chord, &SevenDVec([0.0; 7])), seven_d_dot(&chord, &chord).sqrt());
+        assert!((seven_d_dot(&point, &SevenDVec([1.0; 7])) - 1.2).abs() < 1e-6);
+    }
+    
+    #[test]
+    fn frequency_weights_round_trip() {
+        let point = TrajectoryPoint::from_array(&[0.432, -0.528, 0.639, 0.741, 0.852, 0.963, -0.396]);
+        let weighted = point.apply_frequency_weights();
+        let back = weighted.remove_frequency_weights();
+        
+        for (a, b) in point.to_array().iter().zip(back.to_array().iter()) {
+            assert!((a - b).abs() < 1e-6);
+        }
+        assert_eq!(weighted.void, point.void);
+        assert_eq!(weighted.eigenvalue, point.eigenvalue);
+        assert!((weighted.meta / point.meta - 2.229).abs() < 1e-3);
+    }
+    
+    fn knots() -> [TrajectoryPoint; 5] {
+        [
+            TrajectoryPoint::from_array(&[0.0, 1.0, 0.5, 0.0, 0.2, 0.9, 0.1]),
+            TrajectoryPoint::from_array(&[0.4, 0.8, 0.1, 0.3, 0.2, 0.7, 0.2]),
+            TrajectoryPoint::from_array(&[0.9, 0.2, 0.6, 0.5, 0.8, 0.1, 0.4]),
+            TrajectoryPoint::from_array(&[0.3, 0.3, 0.9, 0.1, 0.5, 0.6, 0.8]),
+            TrajectoryPoint::from_array(&[0.7, 0.6, 0.2, 0.8, 0.3, 0.4, 0.5]),
+        ]
+    }
+    
+    #[test]
+    fn spline_passes_through_its_inner_knots() {
+        let [p0, p1, p2, p3, _] = knots();
+        let start = trajectory_interpolate_cubic(&p0, &p1, &p2, &p3, 0.0);
+        let end = trajectory_interpolate_cubic(&p0, &p1, &p2, &p3, 1.0);
+        
+        for i in 0..7 {
+            assert!((start.dimension(i) - p1.dimension(i)).abs() < 1e-6);
+            assert!((end.dimension(i) - p2.dimension(i)).abs() < 1e-6);
+        }
+    }
+    
+    #[test]
+    fn spline_derivative_is_continuous_across_segments() {
+        let [p0, p1, p2, p3, p4] = knots();
+        let h = 1e-2;
+        
+        // Leaving segment p1→p2 at t = 1 and entering p2→p3 at t = 0
+        let left = |t: f32| trajectory_interpolate_cubic(&p0, &p1, &p2, &p3, t).to_array();
+        let right = |t: f32| trajectory_interpolate_cubic(&p1, &p2, &p3, &p4, t).to_array();
+        let (before, at, after) = (left(1.0 - h), right(0.0), right(h));
+        for i in 0..7 {
+            let incoming = (at[i] - before[i]) / h;
+            let outgoing = (after[i] - at[i]) / h;
+            assert!((incoming - outgoing).abs() < 0.05, "layer {i}: {incoming} vs {outgoing}");
+        }
+        
+        let path = trajectory_spline_path(&knots(), 9);
+        assert_eq!(path.len(), 9);
+        for (a, b) in path[8].to_array().iter().zip(p4.to_array().iter()) {
+            assert!((a - b).abs() < 1e-6);
+        }
+    }
+    
+    #[test]
+    fn frequency_domain_round_trips_every_layer() {
+        let layers = [0.9, -0.2, 0.4, 1.7, 0.0, -1.1, 0.3];
+        let point = TrajectoryPoint::from_array(&layers);
+        let spectrum = point.frequency_domain();
+        
+        assert!((spectrum[0] - layers.iter().sum::<f32>()).abs() < 1e-5);
+        
+        let back = TrajectoryPoint::reconstruct_from_frequency_domain(&spectrum).to_array();
+        for (original, recovered) in layers.iter().zip(back.iter()) {
+            assert!((original - recovered).abs() < 1e-5);
+        }
+    }
+    
+    #[test]
+    fn interpolated_resonance_agrees_with_the_exact_layers() {
+        let point = TrajectoryPoint::from_array(&[0.2, 0.8, 0.5, 0.3, 0.9, 0.1, 0.4]);
+        assert_eq!(point.resonate_interpolated(432), point.resonate(432));
+        assert_eq!(point.resonate_interpolated(639), point.resonate(639));
+        
+        let between = point.resonate_interpolated(480);
+        assert!(between > point.resonate(432) && between < point.resonate(528));
+        
+        let spectrum = point.resonate_all_frequencies(400, 1000, 13);
+        assert_eq!(spectrum.len(), 13);
+        assert_eq!((spectrum[0].0, spectrum[12].0), (400, 1000));
+    }
+}
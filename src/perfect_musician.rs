@@ -11,6 +11,10 @@
 use crate::glyph_hash::GlyphHash;
 use crate::fourier_conduct::conduct;
 use crate::time_spiral::TimeSpiral;
+use crate::time_weaving_loom::TimeWeavingLoom;
+use crate::flower_synthesis::GrandSynthesis;
+use crate::spiral_score::{SpiralScore, SpiralTime};
+use core::f32::consts::TAU;
 
 /// Reader context - who is listening changes what is played
 #[repr(C)]
@@ -27,6 +31,7 @@ pub struct PerfectMusician {
     pub higher_octaves: u8,              // Access to N-dimensional octaves
     pub improvisation_factor: f32,       // How much to deviate from score
     pub reader_sensitivity: f32,         // How much reader affects performance
+    sensitivity_log: Vec<f32>,           // Sensitivity after each feedback
 }
 
 impl PerfectMusician {
@@ -37,9 +42,91 @@ impl PerfectMusician {
             higher_octaves: octaves,
             improvisation_factor: 0.618,  // Golden ratio improvisation
             reader_sensitivity: 0.5,       // 50% reader influence
+            sensitivity_log: Vec::new(),
         }
     }
     
+    /// Learn from the audience: engaged (> 0.5) → follow the reader more,
+    /// confused (< 0.5) → lead more. Each lesson counts less than the last,
+    /// so the sensitivity settles instead of chasing every reaction
+    pub fn adaptive_reader_sensitivity(&mut self, performance_feedback: f32) {
+        const INITIAL_RATE: f32 = 0.5;
+        const RATE_DECAY: f32 = 0.9;
+        
+        let rate = INITIAL_RATE * RATE_DECAY.powi(self.sensitivity_log.len() as i32);
+        let target = if performance_feedback > 0.5 {
+            1.0
+        } else if performance_feedback < 0.5 {
+            0.0
+        } else {
+            self.reader_sensitivity  // Neutral audience, nothing to learn
+        };
+        
+        self.reader_sensitivity += rate * (target - self.reader_sensitivity);
+        self.sensitivity_log.push(self.reader_sensitivity);
+    }
+    
+    /// Reader sensitivity after each feedback, oldest first
+    pub fn sensitivity_history(&self) -> &[f32] {
+        &self.sensitivity_log
+    }
+    
+    /// Remember a soul
+    pub fn register_soul(&mut self, soul: GlyphHash) {
+        self.soul_registry.push(soul);
+    }
+    
+    /// Find the registered soul nearest to the query
+    pub fn recognize_soul(&self, query: &GlyphHash) -> Option<usize> {
+        self.soul_registry.iter()
+            .enumerate()
+            .map(|(i, soul)| (i, soul.distance(query)))
+            .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal))
+            .map(|(i, _)| i)
+    }
+    
+    /// Persist the soul registry: one hex-encoded 60-byte record per line
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_soul_registry(&self, path: &str) -> std::io::Result<()> {
+        use std::fmt::Write as _;
+        
+        let mut contents = String::new();
+        for soul in &self.soul_registry {
+            for byte in soul.to_bytes() {
+                let _ = write!(contents, "{:02x}", byte);
+            }
+            contents.push('\n');
+        }
+        
+        std::fs::write(path, contents)
+    }
+    
+    /// Read a soul registry written by `save_soul_registry`
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_soul_registry(path: &str) -> std::io::Result<Vec<GlyphHash>> {
+        use std::io::{Error, ErrorKind};
+        
+        let contents = std::fs::read_to_string(path)?;
+        let mut registry = Vec::new();
+        
+        for line in contents.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let bytes = (0..line.len())
+                .step_by(2)
+                .map(|i| {
+                    line.get(i..i + 2)
+                        .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                        .ok_or_else(|| Error::new(ErrorKind::InvalidData, "invalid hex in soul record"))
+                })
+                .collect::<std::io::Result<Vec<u8>>>()?;
+            
+            let soul = GlyphHash::from_bytes(&bytes)
+                .ok_or_else(|| Error::new(ErrorKind::InvalidData, "soul record too short"))?;
+            registry.push(soul);
+        }
+        
+        Ok(registry)
+    }
+    
     /// Interpret code as hint, not instruction
     pub fn interpret(
         &self,
@@ -133,22 +220,109 @@ impl PerfectMusician {
     
     /// Calculate harmony coefficient
     fn calculate_harmony(&self, chord: &[f32; 7]) -> f32 {
-        // Harmonic mean of non-zero values
-        let mut sum_reciprocals = 0.0;
-        let mut count = 0;
+        harmony_coefficient(chord)
+    }
+    
+    /// Improvise a whole phrase: each chord rises through the higher
+    /// octaves from the previous one, then settles toward harmony 0.9
+    pub fn improvise_chord_sequence(&self, seed: &[f32; 7], length: usize) -> Vec<[f32; 7]> {
+        let mut sequence = Vec::with_capacity(length);
+        let mut previous = *seed;
         
-        for &value in chord {
-            if value > 0.0 {
-                sum_reciprocals += 1.0 / value;
-                count += 1;
+        for _ in 0..length {
+            let improvised = self.improvise_from_higher_dimensions(&previous, self.higher_octaves);
+            let mut chord = self.find_perfect_chord(&improvised, 0.9);
+            for value in chord.iter_mut() {
+                *value = value.clamp(0.0, 1.0);
             }
+            
+            sequence.push(chord);
+            previous = chord;
         }
         
-        if count > 0 && sum_reciprocals > 0.0 {
-            (count as f32) / sum_reciprocals
-        } else {
-            0.0
+        sequence
+    }
+    
+    /// Markov transition matrix between harmony levels of a phrase
+    /// Row = harmony bin of a chord, column = bin of the next chord
+    pub fn transition_matrix(sequence: &[[f32; 7]], quantization: usize) -> Vec<Vec<f32>> {
+        let mut matrix = vec![vec![0.0f32; quantization]; quantization];
+        if quantization == 0 {
+            return matrix;
+        }
+        
+        let bin = |chord: &[f32; 7]| {
+            let harmony = harmony_coefficient(chord).clamp(0.0, 1.0);
+            ((harmony * quantization as f32) as usize).min(quantization - 1)
+        };
+        
+        for pair in sequence.windows(2) {
+            matrix[bin(&pair[0])][bin(&pair[1])] += 1.0;
+        }
+        
+        // Counts → probabilities
+        for row in matrix.iter_mut() {
+            let total: f32 = row.iter().sum();
+            if total > 0.0 {
+                for p in row.iter_mut() {
+                    *p /= total;
+                }
+            }
         }
+        
+        matrix
+    }
+    
+    /// Play the loom's weave history as a quartet score: step k becomes a
+    /// note at radius k on arm k % 4, at the orbital phase it was woven at,
+    /// as loud as the step's L2 norm
+    pub fn generate_score_for_loom(loom: &TimeWeavingLoom) -> SpiralScore {
+        score_from_steps(&loom.weave_pattern, &loom.phase_history)
+    }
+    
+    /// Play a synthesis' cycle history (one petal per cycle) the same way,
+    /// each petal at the phase its cycle's weave was made at
+    pub fn generate_score_for_synthesis(synthesis: &GrandSynthesis) -> SpiralScore {
+        score_from_steps(&synthesis.flower.petals, &synthesis.loom.phase_history)
+    }
+}
+
+/// Quartet score from a history of 7D steps and the phase each was made at
+/// Steps with no recorded phase (set by hand, or petals grown without a
+/// weave) keep the last recorded one, or 0 before any
+fn score_from_steps(steps: &[[f32; 7]], phases: &[f32]) -> SpiralScore {
+    let mut score = SpiralScore::quartet();
+    
+    for (k, step) in steps.iter().enumerate() {
+        let phase = phases.get(k).or(phases.last()).copied().unwrap_or(0.0);
+        let time = SpiralTime {
+            radius: k as f32,
+            angle: phase.rem_euclid(TAU),
+            layer: (k % 4) as u8,
+        };
+        let amplitude = step.iter().map(|x| x * x).sum::<f32>().sqrt();
+        score.add_note(k % 4, time, amplitude);
+    }
+    
+    score
+}
+
+/// Harmonic mean of the non-zero layers of a chord
+fn harmony_coefficient(chord: &[f32; 7]) -> f32 {
+    let mut sum_reciprocals = 0.0;
+    let mut count = 0;
+    
+    for &value in chord {
+        if value > 0.0 {
+            sum_reciprocals += 1.0 / value;
+            count += 1;
+        }
+    }
+    
+    if count > 0 && sum_reciprocals > 0.0 {
+        (count as f32) / sum_reciprocals
+    } else {
+        0.0
     }
 }
 
@@ -265,4 +439,126 @@ pub extern "C" fn perfection_from_imperfection(
     
     // Perfection emerges through iteration
     (quality * 1.618034) % 1.0  // Golden ratio transformation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn saved_registry_recognizes_its_souls() {
+        let intents = [
+            [0.9, 0.1, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.8, 0.2, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.1, 0.3, 0.7],
+        ];
+        let mut musician = PerfectMusician::transcendent(7);
+        for intent in &intents {
+            musician.register_soul(GlyphHash::from_intent(intent));
+        }
+        
+        let path = std::env::temp_dir().join(format!("soul_registry_{}.hex", std::process::id()));
+        let path = path.to_str().unwrap();
+        musician.save_soul_registry(path).unwrap();
+        let loaded = PerfectMusician::load_soul_registry(path);
+        std::fs::remove_file(path).unwrap();
+        
+        let mut reborn = PerfectMusician::transcendent(7);
+        reborn.soul_registry = loaded.unwrap();
+        assert_eq!(reborn.soul_registry.len(), 3);
+        
+        // A slightly detuned copy of the second soul is still recognized
+        let query = GlyphHash::from_intent(&[0.0, 0.05, 0.75, 0.2, 0.0, 0.0, 0.0]);
+        assert_eq!(reborn.recognize_soul(&query), Some(1));
+        assert_eq!(PerfectMusician::transcendent(7).recognize_soul(&query), None);
+    }
+    
+    #[test]
+    fn improvised_sequence_has_the_requested_length_and_range() {
+        let musician = PerfectMusician::transcendent(7);
+        let seed = [0.432, 0.528, 0.639, 0.741, 0.852, 0.963, 0.396];
+        
+        let sequence = musician.improvise_chord_sequence(&seed, 16);
+        assert_eq!(sequence.len(), 16);
+        assert!(sequence.iter().flatten().all(|value| (0.0..=1.0).contains(value)));
+        assert!(musician.improvise_chord_sequence(&seed, 0).is_empty());
+    }
+    
+    #[test]
+    fn transition_rows_are_distributions() {
+        let musician = PerfectMusician::transcendent(7);
+        let sequence = musician.improvise_chord_sequence(&[0.5; 7], 32);
+        let matrix = PerfectMusician::transition_matrix(&sequence, 4);
+        
+        assert_eq!(matrix.len(), 4);
+        for row in &matrix {
+            assert_eq!(row.len(), 4);
+            let total: f32 = row.iter().sum();
+            assert!(total == 0.0 || (total - 1.0).abs() < 1e-6);
+        }
+        let visited: f32 = matrix.iter().flatten().sum();
+        assert!(visited >= 1.0 - 1e-6);
+    }
+    
+    #[test]
+    fn sensitivity_settles_under_constant_feedback() {
+        let mut engaged = PerfectMusician::transcendent(7);
+        let mut confused = PerfectMusician::transcendent(7);
+        for _ in 0..50 {
+            engaged.adaptive_reader_sensitivity(0.9);
+            confused.adaptive_reader_sensitivity(0.1);
+        }
+        
+        let history = engaged.sensitivity_history();
+        assert_eq!(history.len(), 50);
+        assert!((history[49] - history[48]).abs() < 1e-3);
+        assert!(history.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!(engaged.reader_sensitivity > 0.9 && engaged.reader_sensitivity <= 1.0);
+        assert!(confused.reader_sensitivity < 0.1 && confused.reader_sensitivity >= 0.0);
+    }
+    
+    #[test]
+    fn loom_score_has_a_note_per_weave_step() {
+        let mut loom = TimeWeavingLoom::new(&[0.5; 7]);
+        for _ in 0..12 {
+            loom.weave(&[0.8, 0.7, 0.6, 0.5, 0.4, 0.3, 0.2], &[0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8]);
+        }
+        
+        let score = PerfectMusician::generate_score_for_loom(&loom);
+        assert_eq!(score.notes.len(), loom.weave_pattern.len());
+        assert!(score.notes[0].time.angle.abs() < 1e-5);  // The first weave starts at phase 0
+        assert_eq!(score.notes[5].time.layer, 1);
+        
+        let mut synthesis = GrandSynthesis::from_now(&[0.5; 7]);
+        for _ in 0..5 {
+            synthesis.synthesize_cycle();
+        }
+        let score = PerfectMusician::generate_score_for_synthesis(&synthesis);
+        assert_eq!(score.notes.len(), synthesis.flower.petals.len());
+    }
+    
+    #[test]
+    fn loom_score_follows_a_phase_lock_mid_weave() {
+        let (forward, backward) = ([0.8; 7], [0.2; 7]);
+        let mut loom = TimeWeavingLoom::new(&[0.5; 7]);
+        for _ in 0..3 {
+            loom.weave(&forward, &backward);
+        }
+        loom.synchronize_phase(2.0, 10.0);
+        for _ in 0..3 {
+            loom.weave(&forward, &backward);
+        }
+        
+        let score = PerfectMusician::generate_score_for_loom(&loom);
+        let angles: Vec<f32> = score.notes.iter().map(|note| note.time.angle).collect();
+        let expected = [0.0, 0.1, 0.2, 2.0, 2.1, 2.2];
+        for (angle, phase) in angles.iter().zip(expected.iter()) {
+            assert!((angle - phase).abs() < 1e-5, "{:?}", angles);
+        }
+        
+        loom.trim_history(2);
+        let trimmed = PerfectMusician::generate_score_for_loom(&loom);
+        assert_eq!(trimmed.notes.len(), 2);
+        assert!((trimmed.notes[0].time.angle - 2.1).abs() < 1e-5);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,827 @@
+//! ₴-Origin: Ramanujan Mirror
+//!
+//! Every transform has a mirror where it dissolves back into its source.
+//! e^(π√163) almost touches an integer - the mirror almost touches truth.
+//!
+//! "A formula is a reflection that remembers its original."
+
+#![cfg_attr(target_arch = "wasm32", no_std)]
+
+use core::f64::consts::PI;
+
+/// The Ramanujan transform: x → e^(π√x)
+#[no_mangle]
+pub extern "C" fn ramanujan_transform(x: f64) -> f64 {
+    // Negative inputs have no real root - reflect them to zero
+    (PI * x.max(0.0).sqrt()).exp()
+}
+
+/// The mirror of the transform: y → (ln(y) / π)²
+/// Only values above 1.0 have a source (e^(π√x) ≥ 1 for all x ≥ 0)
+pub fn inverse_ramanujan_transform(y: f64) -> Option<f64> {
+    if y > 1.0 {
+        let root = y.ln() / PI;
+        Some(root * root)
+    } else {
+        None
+    }
+}
+
+/// Walk through the mirror and back until the reflection settles
+/// The round trip is the identity, so the fixed point is the input itself
+#[no_mangle]
+pub extern "C" fn ramanujan_fixed_point(initial_y: f64, iterations: u32) -> f64 {
+    let mut x = initial_y;
+    
+    for _ in 0..iterations {
+        match inverse_ramanujan_transform(ramanujan_transform(x)) {
+            Some(reflected) => x = reflected,
+            None => break,  // Fell out of the mirror (x = 0)
+        }
+    }
+    
+    x
+}
+
+/// The Ramanujan orbit: x₀, T(x₀), T(T(x₀)), ...
+pub fn ramanujan_sequence(x0: f64, n: u32) -> Vec<f64> {
+    let mut sequence = Vec::with_capacity(n as usize);
+    let mut x = x0;
+    
+    for _ in 0..n {
+        sequence.push(x);
+        x = ramanujan_transform(x);
+    }
+    
+    sequence
+}
+
+/// Every point where the mirror shows its own reflection: f(x) = x
+/// Bisection on each segment of the domain where f(x) - x changes sign
+pub fn find_all_fixed_points(
+    f: fn(f64) -> f64,
+    domain: (f64, f64),
+    n_segments: usize
+) -> Vec<f64> {
+    let mut fixed_points: Vec<f64> = Vec::new();
+    let (start, end) = domain;
+    
+    if n_segments == 0 || end <= start {
+        return fixed_points;
+    }
+    
+    let g = |x: f64| f(x) - x;
+    let width = (end - start) / n_segments as f64;
+    
+    for segment in 0..n_segments {
+        let mut a = start + segment as f64 * width;
+        let mut b = if segment + 1 == n_segments { end } else { a + width };
+        let (mut ga, gb) = (g(a), g(b));
+        
+        let found = if ga == 0.0 {
+            Some(a)
+        } else if gb == 0.0 {
+            Some(b)
+        } else if ga * gb < 0.0 {
+            // Halve the segment until the reflection is pinned down
+            for _ in 0..100 {
+                let mid = 0.5 * (a + b);
+                let gm = g(mid);
+                if gm == 0.0 || (b - a) < 1e-12 {
+                    a = mid;
+                    b = mid;
+                    break;
+                }
+                if ga * gm < 0.0 {
+                    b = mid;
+                } else {
+                    a = mid;
+                    ga = gm;
+                }
+            }
+            Some(0.5 * (a + b))
+        } else {
+            None
+        };
+        
+        if let Some(x) = found {
+            // Neighbouring segments may share a root on their border
+            if !fixed_points.iter().any(|&p| (p - x).abs() < 1e-8) {
+                fixed_points.push(x);
+            }
+        }
+    }
+    
+    fixed_points
+}
+
+/// How strongly the mirror amplifies a small push away from x
+/// < 1 = stable attractor, > 1 = unstable repeller
+pub fn fixed_point_stability(f: fn(f64) -> f64, x: f64, perturbation: f64) -> f32 {
+    if perturbation == 0.0 {
+        return 0.0;
+    }
+    
+    ((f(x + perturbation) - f(x)).abs() / perturbation.abs()) as f32
+}
+
+/// Distance of a proof step (x, y) from the truth line y = x
+fn truth_distance(step: &(f64, f64)) -> f64 {
+    (step.1 - step.0).abs() / core::f64::consts::SQRT_2
+}
+
+/// Distribution of proof step distances from the truth line
+/// Returns `bins` (upper_bound, count) pairs covering [0, max_distance]
+pub fn proof_elegance_histogram(steps: &[(f64, f64)], bins: usize) -> Vec<(f64, usize)> {
+    if bins == 0 {
+        return Vec::new();
+    }
+    
+    let distances: Vec<f64> = steps.iter().map(truth_distance).collect();
+    let max_distance = distances.iter().cloned().fold(0.0, f64::max);
+    
+    let mut histogram: Vec<(f64, usize)> = (0..bins)
+        .map(|k| (max_distance * (k + 1) as f64 / bins as f64, 0))
+        .collect();
+    
+    for d in distances {
+        let bin = if max_distance > 0.0 {
+            ((d / max_distance) * bins as f64) as usize
+        } else {
+            0
+        };
+        histogram[bin.min(bins - 1)].1 += 1;
+    }
+    
+    histogram
+}
+
+/// Indices of the "ugly" steps: more than `z_threshold` standard
+/// deviations farther from the truth line than the mean step
+pub fn proof_outlier_steps(steps: &[(f64, f64)], z_threshold: f64) -> Vec<usize> {
+    if steps.is_empty() {
+        return Vec::new();
+    }
+    
+    let distances: Vec<f64> = steps.iter().map(truth_distance).collect();
+    let n = distances.len() as f64;
+    let mean = distances.iter().sum::<f64>() / n;
+    let std_dev = (distances.iter().map(|d| (d - mean) * (d - mean)).sum::<f64>() / n).sqrt();
+    
+    if std_dev == 0.0 {
+        return Vec::new();  // Perfectly even proof
+    }
+    
+    distances.iter()
+        .enumerate()
+        .filter(|(_, &d)| (d - mean) / std_dev > z_threshold)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// The nearest palindromic polynomial: each coefficient meets its mirror
+/// halfway, coeffs[i] and coeffs[degree - i] both become their average
+pub fn polynomial_projection_to_truth(coeffs: &[f64]) -> Vec<f64> {
+    coeffs.iter()
+        .zip(coeffs.iter().rev())
+        .map(|(a, b)| 0.5 * (a + b))
+        .collect()
+}
+
+/// L2 distance from a polynomial to its nearest palindrome
+pub fn palindromic_distance(coeffs: &[f64]) -> f64 {
+    coeffs.iter()
+        .zip(polynomial_projection_to_truth(coeffs))
+        .map(|(a, b)| (a - b) * (a - b))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Fit a polynomial of the given degree to f on [-1, 1] by least squares,
+/// then pull its coefficients (lowest power first) onto the palindrome
+pub fn symmetrize_function(f: fn(f64) -> f64, degree: usize) -> Vec<f64> {
+    let terms = degree + 1;
+    let samples = 4 * terms;
+    
+    // Normal equations VᵀV c = Vᵀy of the Vandermonde system
+    let mut system = vec![vec![0.0f64; terms + 1]; terms];
+    for s in 0..samples {
+        let x = -1.0 + 2.0 * s as f64 / (samples - 1) as f64;
+        let y = f(x);
+        let powers: Vec<f64> = (0..terms).map(|p| x.powi(p as i32)).collect();
+        
+        for (row, &pr) in system.iter_mut().zip(powers.iter()) {
+            for (cell, &pc) in row.iter_mut().zip(powers.iter()) {
+                *cell += pr * pc;
+            }
+            row[terms] += pr * y;
+        }
+    }
+    
+    polynomial_projection_to_truth(&gaussian_elimination(system))
+}
+
+/// Solve an augmented n × (n+1) system with partial pivoting
+/// Singular directions are left at zero
+fn gaussian_elimination(mut system: Vec<Vec<f64>>) -> Vec<f64> {
+    let n = system.len();
+    
+    for col in 0..n {
+        let pivot = (col..n)
+            .max_by(|&a, &b| system[a][col].abs().total_cmp(&system[b][col].abs()))
+            .unwrap_or(col);
+        system.swap(col, pivot);
+        
+        if system[col][col].abs() < 1e-12 {
+            continue;
+        }
+        
+        let (upper, lower) = system.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for row in lower.iter_mut() {
+            let factor = row[col] / pivot_row[col];
+            for (cell, p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *cell -= factor * p;
+            }
+        }
+    }
+    
+    let mut solution = vec![0.0f64; n];
+    for row in (0..n).rev() {
+        if system[row][row].abs() < 1e-12 {
+            continue;
+        }
+        let known: f64 = (row + 1..n).map(|k| system[row][k] * solution[k]).sum();
+        solution[row] = (system[row][n] - known) / system[row][row];
+    }
+    
+    solution
+}
+
+/// Meet each value halfway with its reciprocal: x → (x + 1/x) / 2
+/// 1.0 is the fixed point; the mirror diverges as x → 0
+pub fn mirror_harmonic_transform(sequence: &[f64]) -> Vec<f64> {
+    sequence.iter().map(|&x| (x + 1.0 / x) / 2.0).collect()
+}
+
+/// Keep only the values lying close to the mirror axis: |x - 1/x| / 2 < threshold
+pub fn mirror_harmonic_filter(sequence: &[f64], threshold: f64) -> Vec<f64> {
+    sequence.iter()
+        .copied()
+        .filter(|&x| ((x - 1.0 / x) / 2.0).abs() < threshold)
+        .collect()
+}
+
+/// Shannon entropy of the transformed sequence read as a distribution
+/// (magnitudes normalized to sum 1; divergent values are left out)
+pub fn mirror_harmonic_entropy(sequence: &[f64]) -> f64 {
+    let weights: Vec<f64> = mirror_harmonic_transform(sequence)
+        .into_iter()
+        .filter(|y| y.is_finite())
+        .map(f64::abs)
+        .collect();
+    let total: f64 = weights.iter().sum();
+    
+    if total <= 0.0 {
+        return 0.0;
+    }
+    
+    weights.iter()
+        .map(|w| w / total)
+        .filter(|&p| p > 0.0)
+        .map(|p| -p * p.ln())
+        .sum()
+}
+
+/// Sample f at golden-angle points x = cos(k · golden_angle), k in 0..n
+/// Returns (x, f(x), distance of (x, f(x)) from the mirror line y = x)
+pub fn golden_angle_map(f: fn(f64) -> f64, n: u32) -> Vec<(f64, f64, f64)> {
+    let golden_angle = PI * (3.0 - 5.0f64.sqrt());
+    
+    (0..n)
+        .map(|k| {
+            let x = (k as f64 * golden_angle).cos();
+            let y = f(x);
+            (x, y, truth_distance(&(x, y)))
+        })
+        .collect()
+}
+
+/// Mean distance of the golden-angle samples from the mirror (0 = f is the mirror)
+pub fn golden_angle_symmetry_score(f: fn(f64) -> f64, n: u32) -> f64 {
+    if n == 0 {
+        return 0.0;
+    }
+    
+    golden_angle_map(f, n).iter().map(|&(_, _, d)| d).sum::<f64>() / n as f64
+}
+
+/// The golden ratio's continued fraction [1; 1, 1, ...] cut after `terms` levels:
+/// 1, 2, 3/2, 5/3, 8/5, ... (ratios of consecutive Fibonacci numbers)
+pub fn golden_ratio_continued_fraction(terms: u32) -> f64 {
+    let mut approx = 1.0;
+    for _ in 1..terms {
+        approx = 1.0 + 1.0 / approx;
+    }
+    approx
+}
+
+/// |φ - approxₙ| / |φ - approxₙ₋₁|: how much each extra level shrinks the error
+/// The slowest of all continued fractions - the ratio tends to 1/φ² ≈ 0.382
+pub fn continued_fraction_convergence_rate(terms: u32) -> f64 {
+    let phi = (1.0 + 5.0f64.sqrt()) / 2.0;
+    let previous_error = (phi - golden_ratio_continued_fraction(terms.saturating_sub(1))).abs();
+    
+    if previous_error == 0.0 {
+        return 0.0;
+    }
+    (phi - golden_ratio_continued_fraction(terms)).abs() / previous_error
+}
+
+/// The continued fraction with every convergent walked through the mirror
+/// and back; the mirror is exact, so any difference from
+/// `golden_ratio_continued_fraction` is numerical drift in the reflection
+pub fn mirror_continued_fraction(terms: u32) -> f64 {
+    let mut approx = 1.0;
+    for _ in 1..terms {
+        approx = 1.0 + 1.0 / approx;
+        if let Some(reflected) = inverse_ramanujan_transform(ramanujan_transform(approx)) {
+            approx = reflected;
+        }
+    }
+    approx
+}
+
+/// The harmonic conjugate of f on the real line at x - its Hilbert
+/// transform (1/π) p.v. ∫ f(t) / (x - t) dt, summed with the midpoint rule
+/// over x ± 100. Samples sit symmetrically at half-steps around x, so the
+/// singularity is never evaluated and its two sides cancel (principal value)
+pub fn harmonic_conjugate(f: fn(f64) -> f64, x: f64, step: f64) -> f64 {
+    const HALF_RANGE: f64 = 100.0;
+    
+    if step <= 0.0 {
+        return 0.0;
+    }
+    
+    let samples = (HALF_RANGE / step).ceil() as i64;
+    let integral: f64 = (-samples..samples)
+        .map(|k| {
+            let t = x + (k as f64 + 0.5) * step;
+            f(t) / (x - t)
+        })
+        .sum::<f64>() * step;
+    
+    integral / PI
+}
+
+/// Do u and v satisfy the Cauchy-Riemann equations at (x, y)?
+/// ∂u/∂x = ∂v/∂y and ∂u/∂y = -∂v/∂x by central differences with step h,
+/// each within h (relative to the larger derivative when above 1)
+/// u and v take (x, y): a one-variable function has no ∂/∂y to compare
+pub fn verify_cauchy_riemann(
+    u: fn(f64, f64) -> f64,
+    v: fn(f64, f64) -> f64,
+    x: f64,
+    y: f64,
+    h: f64
+) -> bool {
+    if h <= 0.0 {
+        return false;
+    }
+    
+    let du_dx = (u(x + h, y) - u(x - h, y)) / (2.0 * h);
+    let du_dy = (u(x, y + h) - u(x, y - h)) / (2.0 * h);
+    let dv_dx = (v(x + h, y) - v(x - h, y)) / (2.0 * h);
+    let dv_dy = (v(x, y + h) - v(x, y - h)) / (2.0 * h);
+    
+    let close = |a: f64, b: f64| (a - b).abs() <= h * a.abs().max(b.abs()).max(1.0);
+    close(du_dx, dv_dy) && close(du_dy, -dv_dx)
+}
+
+/// How well f remembers its original through the mirror y = x:
+/// 1 / (1 + mean |f(f(x)) - x|) over `samples` midpoints of [0, 1]
+/// 1.0 = f is its own reflection (an involution), → 0 = f forgets
+pub fn reflection_coefficient(f: fn(f64) -> f64, samples: u32) -> f64 {
+    if samples == 0 {
+        return 0.0;
+    }
+    
+    let forgetting = (0..samples)
+        .map(|k| {
+            let x = (k as f64 + 0.5) / samples as f64;
+            (f(f(x)) - x).abs()
+        })
+        .sum::<f64>() / samples as f64;
+    
+    1.0 / (1.0 + forgetting)
+}
+
+/// Slots in a `ReflectionCache`
+const REFLECTION_CACHE_SIZE: usize = 16;
+
+/// A function, the sample count used and its cached reflection coefficient
+pub type ReflectionEntry = (fn(f64) -> f64, u32, f64);
+
+/// Memoized reflection coefficients, keyed by function pointer and sample
+/// count (a coarse estimate never stands in for a finer one)
+/// When full, the least recently used entry is forgotten
+pub struct ReflectionCache {
+    pub entries: [ReflectionEntry; REFLECTION_CACHE_SIZE],  // Most recent first
+    pub len: usize,                                          // Slots in use
+}
+
+impl ReflectionCache {
+    /// An empty cache
+    pub fn new() -> Self {
+        ReflectionCache {
+            entries: [(vacant as fn(f64) -> f64, 0, 0.0); REFLECTION_CACHE_SIZE],
+            len: 0,
+        }
+    }
+    
+    /// The cached coefficient of f at this sample count, computing it on
+    /// first sight
+    pub fn get_or_compute(&mut self, f: fn(f64) -> f64, samples: u32) -> f64 {
+        let hit = self.entries[..self.len]
+            .iter()
+            .position(|&(cached, n, _)| core::ptr::fn_addr_eq(cached, f) && n == samples);
+        
+        match hit {
+            Some(i) => {
+                // Move to the front: most recently used
+                self.entries[..=i].rotate_right(1);
+            }
+            None => {
+                let coefficient = reflection_coefficient(f, samples);
+                
+                // The least recently used entry falls off the end when full
+                self.len = (self.len + 1).min(REFLECTION_CACHE_SIZE);
+                self.entries[..self.len].rotate_right(1);
+                self.entries[0] = (f, samples, coefficient);
+            }
+        }
+        
+        self.entries[0].2
+    }
+}
+
+impl Default for ReflectionCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Placeholder for unused cache slots
+fn vacant(x: f64) -> f64 {
+    x
+}
+
+/// A proof you can look at: how far each point of the plane stands from
+/// the graph of a function
+pub struct VisualProof {
+    pub resolution: usize,  // Cells per side
+    pub extent: f64,        // The map covers [-extent, extent]²
+}
+
+impl VisualProof {
+    /// A square map of `resolution` × `resolution` cells
+    pub fn new(resolution: usize, extent: f64) -> Self {
+        VisualProof { resolution, extent }
+    }
+    
+    /// Cell [row][col] holds |f(x) - y| at the cell center (x grows with
+    /// col, y with row): zero along the graph, symmetric for a mirror
+    pub fn generate_truth_map(&self, f: fn(f64) -> f64) -> Vec<Vec<f64>> {
+        self.truth_map(f)
+    }
+    
+    /// A time-dependent f(x, t) as a film: frame i is the truth map of
+    /// x → f(x, t) at t = i / frames, so t sweeps [0, 1)
+    pub fn animate(&self, f: fn(f64, f64) -> f64, frames: usize) -> Vec<Vec<Vec<f64>>> {
+        (0..frames)
+            .map(|i| {
+                let t = i as f64 / frames as f64;
+                self.truth_map(|x| f(x, t))
+            })
+            .collect()
+    }
+    
+    /// g pulled toward its reflection: frame i shows (1 - t)·g + t·projected_g
+    /// at t = i / frames, with projected_g from `project_to_truth`
+    pub fn animate_convergence_to_truth(
+        &self,
+        g: fn(f64) -> f64,
+        frames: usize
+    ) -> Vec<Vec<Vec<f64>>> {
+        let projected = Self::project_to_truth(g);
+        
+        (0..frames)
+            .map(|i| {
+                let t = i as f64 / frames as f64;
+                self.truth_map(|x| (1.0 - t) * g(x) + t * projected(x))
+            })
+            .collect()
+    }
+    
+    /// The nearest mirror-true shape of g: its even part (g(x) + g(-x)) / 2,
+    /// whose truth map is symmetric about x = 0
+    pub fn project_to_truth(g: fn(f64) -> f64) -> impl Fn(f64) -> f64 {
+        move |x| 0.5 * (g(x) + g(-x))
+    }
+    
+    /// Center of cell `index` along either axis
+    fn cell_center(&self, index: usize) -> f64 {
+        -self.extent + 2.0 * self.extent * (index as f64 + 0.5) / self.resolution as f64
+    }
+    
+    fn truth_map(&self, f: impl Fn(f64) -> f64) -> Vec<Vec<f64>> {
+        let graph: Vec<f64> = (0..self.resolution).map(|col| f(self.cell_center(col))).collect();
+        
+        (0..self.resolution)
+            .map(|row| {
+                let y = self.cell_center(row);
+                graph.iter().map(|fx| (fx - y).abs()).collect()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn inverse_undoes_the_transform() {
+        for x in [0.25, 1.0, 2.0, 9.0, 163.0] {
+            let back = inverse_ramanujan_transform(ramanujan_transform(x)).unwrap();
+            assert!((back - x).abs() < 1e-9 * x.max(1.0), "{x} came back as {back}");
+        }
+        assert_eq!(inverse_ramanujan_transform(1.0), None);
+        assert_eq!(inverse_ramanujan_transform(0.5), None);
+    }
+    
+    #[test]
+    fn fixed_point_is_the_input_and_sequence_follows_the_orbit() {
+        assert!((ramanujan_fixed_point(2.5, 10) - 2.5).abs() < 1e-9);
+        
+        let sequence = ramanujan_sequence(0.5, 3);
+        assert_eq!(sequence.len(), 3);
+        assert_eq!(sequence[0], 0.5);
+        assert_eq!(sequence[1], ramanujan_transform(0.5));
+        assert_eq!(sequence[2], ramanujan_transform(sequence[1]));
+    }
+    
+    /// Fixed points where (x + 0.5)(x - 1)(x - 2) vanishes
+    fn cubic(x: f64) -> f64 {
+        x + (x + 0.5) * (x - 1.0) * (x - 2.0)
+    }
+    
+    #[test]
+    fn finds_all_three_fixed_points_of_a_cubic() {
+        let fixed_points = find_all_fixed_points(cubic, (-3.0, 3.0), 7);
+        
+        assert_eq!(fixed_points.len(), 3);
+        for (found, expected) in fixed_points.iter().zip([-0.5, 1.0, 2.0]) {
+            assert!((found - expected).abs() < 1e-9);
+        }
+    }
+    
+    #[test]
+    fn stability_separates_attractors_from_repellers() {
+        assert!(fixed_point_stability(cubic, 1.0, 1e-6) < 1.0);
+        assert!(fixed_point_stability(cubic, -0.5, 1e-6) > 1.0);
+        assert!(fixed_point_stability(cubic, 2.0, 1e-6) > 1.0);
+    }
+    
+    /// Steps (x, y) whose distance from the truth line y = x grows with k
+    fn proof_steps() -> Vec<(f64, f64)> {
+        let mut steps: Vec<(f64, f64)> = (0..19).map(|k| (k as f64, k as f64 + 0.01 * k as f64)).collect();
+        steps.push((1.0, 9.0));  // One ugly leap
+        steps
+    }
+    
+    #[test]
+    fn histogram_counts_every_step() {
+        let steps = proof_steps();
+        for bins in [1, 3, 7, 20] {
+            let histogram = proof_elegance_histogram(&steps, bins);
+            assert_eq!(histogram.len(), bins);
+            assert_eq!(histogram.iter().map(|&(_, count)| count).sum::<usize>(), steps.len());
+        }
+        assert!(proof_elegance_histogram(&steps, 0).is_empty());
+    }
+    
+    #[test]
+    fn outliers_are_the_ugly_steps() {
+        assert_eq!(proof_outlier_steps(&proof_steps(), 3.0), vec![19]);
+        assert!(proof_outlier_steps(&[(1.0, 1.0), (2.0, 2.0)], 0.0).is_empty());
+    }
+    
+    static MIRRORED: core::sync::atomic::AtomicUsize = core::sync::atomic::AtomicUsize::new(0);
+    
+    /// 1 - x, its own reflection, counting every look into the mirror
+    fn counted_mirror(x: f64) -> f64 {
+        MIRRORED.fetch_add(1, core::sync::atomic::Ordering::SeqCst);
+        1.0 - x
+    }
+    
+    #[test]
+    fn involutions_reflect_perfectly() {
+        assert_eq!(reflection_coefficient(|x| x, 100), 1.0);
+        assert!((reflection_coefficient(|x| 1.0 / x, 100) - 1.0).abs() < 1e-12);
+        assert!(reflection_coefficient(f64::sqrt, 100) < 1.0);
+        assert!(reflection_coefficient(f64::exp, 100) < reflection_coefficient(f64::sqrt, 100));
+        assert_eq!(reflection_coefficient(f64::exp, 0), 0.0);
+    }
+    
+    #[test]
+    fn cache_computes_each_function_once() {
+        let mut cache = ReflectionCache::new();
+        
+        let first = cache.get_or_compute(counted_mirror, 50);
+        assert_eq!(MIRRORED.load(core::sync::atomic::Ordering::SeqCst), 100);
+        
+        let second = cache.get_or_compute(counted_mirror, 50);
+        assert_eq!(MIRRORED.load(core::sync::atomic::Ordering::SeqCst), 100);
+        assert_eq!(first, second);
+        assert_eq!(cache.len, 1);
+        
+        // A finer sample count is a different estimate, not a cache hit
+        cache.get_or_compute(counted_mirror, 500);
+        assert_eq!(MIRRORED.load(core::sync::atomic::Ordering::SeqCst), 1100);
+        assert_eq!(cache.len, 2);
+        assert_eq!(cache.entries[0].1, 500);
+    }
+    
+    #[test]
+    fn cache_forgets_the_least_recently_used() {
+        let shapes: [fn(f64) -> f64; 17] = [
+            |x| x, |x| x * x, f64::sqrt, f64::sin, f64::exp, f64::cos, f64::ln, f64::tan,
+            |x| 2.0 * x, |x| x + 1.0, |x| -x, |x| x * x * x, f64::cbrt, f64::atan, f64::sinh,
+            f64::cosh, f64::tanh,
+        ];
+        let mut cache = ReflectionCache::default();
+        for &f in &shapes[..16] {
+            cache.get_or_compute(f, 10);
+        }
+        
+        // Touch the oldest so the second oldest is evicted instead
+        cache.get_or_compute(shapes[0], 10);
+        cache.get_or_compute(shapes[16], 10);
+        
+        let cached = |f: fn(f64) -> f64| cache.entries[..cache.len]
+            .iter()
+            .any(|&(g, _, _)| core::ptr::fn_addr_eq(g, f));
+        assert_eq!(cache.len, 16);
+        assert!(cached(shapes[0]));
+        assert!(!cached(shapes[1]));
+        assert!(cached(shapes[16]));
+        assert!(core::ptr::fn_addr_eq(cache.entries[0].0, shapes[16]));
+    }
+    
+    #[test]
+    fn animation_has_one_square_map_per_frame() {
+        let proof = VisualProof::new(8, 2.0);
+        let frames = proof.animate(|x, t| (x - t).sin(), 5);
+        
+        assert_eq!(frames.len(), 5);
+        for frame in &frames {
+            assert_eq!(frame.len(), 8);
+            assert!(frame.iter().all(|row| row.len() == 8));
+        }
+        assert_eq!(frames[0], proof.generate_truth_map(f64::sin));
+        assert!(proof.animate(|x, t| x * t, 0).is_empty());
+    }
+    
+    #[test]
+    fn the_mirror_draws_a_symmetric_map_that_travels() {
+        let proof = VisualProof::new(6, 1.5);
+        let map = proof.generate_truth_map(|x| x);
+        
+        for (row, cells) in map.iter().enumerate() {
+            assert_eq!(cells[row], 0.0);
+            for (col, &cell) in cells.iter().enumerate() {
+                assert_eq!(cell, map[col][row]);
+            }
+        }
+        
+        // Halfway through, the line has shifted right by two 0.5-wide cells
+        let moved = &proof.animate(|x, t| x - 2.0 * t, 2)[1];
+        assert!(moved[0][2].abs() < 1e-12);
+    }
+    
+    #[test]
+    fn palindromes_are_already_true() {
+        let palindrome = [1.0, -2.0, 3.5, -2.0, 1.0];
+        assert_eq!(polynomial_projection_to_truth(&palindrome), palindrome.to_vec());
+        assert_eq!(palindromic_distance(&palindrome), 0.0);
+        
+        assert_eq!(polynomial_projection_to_truth(&[1.0, 2.0, 4.0]), vec![2.5, 2.0, 2.5]);
+        assert!((palindromic_distance(&[1.0, 2.0, 4.0]) - 4.5f64.sqrt()).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn symmetrize_recovers_a_fitted_palindrome() {
+        fn square_of_successor(x: f64) -> f64 {
+            (1.0 + x) * (1.0 + x)
+        }
+        
+        let coeffs = symmetrize_function(square_of_successor, 2);
+        for (fitted, expected) in coeffs.iter().zip([1.0, 2.0, 1.0]) {
+            assert!((fitted - expected).abs() < 1e-9);
+        }
+        for fitted in symmetrize_function(|x| x, 1) {
+            assert!((fitted - 0.5).abs() < 1e-9);
+        }
+    }
+    
+    #[test]
+    fn unity_is_the_mirror_fixed_point() {
+        assert_eq!(mirror_harmonic_transform(&[1.0, 1.0, 1.0]), vec![1.0, 1.0, 1.0]);
+        assert_eq!(mirror_harmonic_transform(&[2.0, 0.5]), vec![1.25, 1.25]);
+        
+        assert_eq!(mirror_harmonic_filter(&[1.0, 1.1, 2.0, 0.5, 0.0], 0.2), vec![1.0, 1.1]);
+        assert!((mirror_harmonic_entropy(&[1.0, 1.0, 1.0]) - 3.0f64.ln()).abs() < 1e-12);
+        assert_eq!(mirror_harmonic_entropy(&[0.0]), 0.0);
+    }
+    
+    #[test]
+    fn identity_lies_on_the_mirror() {
+        assert_eq!(golden_angle_symmetry_score(|x| x, 50), 0.0);
+        assert!(golden_angle_symmetry_score(|x| -x, 50) > 0.1);
+        
+        let samples = golden_angle_map(|x| x * x, 5);
+        assert_eq!(samples.len(), 5);
+        assert_eq!(samples[0], (1.0, 1.0, 0.0));
+    }
+    
+    #[test]
+    fn continued_fraction_closes_in_on_phi() {
+        let phi = (1.0 + 5.0f64.sqrt()) / 2.0;
+        assert_eq!(golden_ratio_continued_fraction(3), 1.5);
+        assert!((golden_ratio_continued_fraction(40) - phi).abs() < 1e-12);
+        
+        // Each level shrinks the error by a factor of φ², the slowest of any continued fraction
+        for terms in [10, 15, 20] {
+            assert!((continued_fraction_convergence_rate(terms) - 1.0 / (phi * phi)).abs() < 1e-3);
+        }
+        assert!((mirror_continued_fraction(30) - golden_ratio_continued_fraction(30)).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn saddle_and_its_conjugate_satisfy_cauchy_riemann() {
+        let u = |x: f64, y: f64| x * x - y * y;
+        let v = |x: f64, y: f64| 2.0 * x * y;
+        
+        for &(x, y) in &[(0.0, 0.0), (1.5, -0.5), (-2.0, 3.0)] {
+            assert!(verify_cauchy_riemann(u, v, x, y, 1e-3), "({x}, {y})");
+        }
+        assert!(!verify_cauchy_riemann(u, |x, y| -2.0 * x * y, 1.5, -0.5, 1e-3));
+        assert!(!verify_cauchy_riemann(u, v, 1.5, -0.5, 0.0));
+    }
+    
+    #[test]
+    fn hilbert_transform_of_the_lorentzian() {
+        // 1/(1+t²) is the real part of i/(x+i) on the line; its conjugate is x/(1+x²)
+        let lorentzian = |t: f64| 1.0 / (1.0 + t * t);
+        
+        for &x in &[-2.0, 0.0, 1.0, 3.0] {
+            let conjugate = harmonic_conjugate(lorentzian, x, 1e-3);
+            assert!((conjugate - x / (1.0 + x * x)).abs() < 1e-2, "{x}: {conjugate}");
+        }
+        assert_eq!(harmonic_conjugate(lorentzian, 1.0, 0.0), 0.0);
+    }
+    
+    #[test]
+    fn convergence_to_truth_has_one_square_map_per_frame() {
+        let proof = VisualProof::new(7, 1.0);
+        let frames = proof.animate_convergence_to_truth(f64::exp, 4);
+        
+        assert_eq!(frames.len(), 4);
+        for frame in &frames {
+            assert_eq!(frame.len(), 7);
+            assert!(frame.iter().all(|row| row.len() == 7));
+        }
+        assert_eq!(frames[0], proof.generate_truth_map(f64::exp));
+        assert!(proof.animate_convergence_to_truth(f64::exp, 0).is_empty());
+    }
+    
+    #[test]
+    fn projection_keeps_the_even_part() {
+        let projected = VisualProof::project_to_truth(f64::exp);
+        for x in [-1.5, -0.3, 0.0, 0.7, 2.0] {
+            assert!((projected(x) - x.cosh()).abs() < 1e-12, "{x}");
+        }
+        
+        // cos is already mirror-true: every frame is the same map
+        let proof = VisualProof::new(5, 1.0);
+        let frames = proof.animate_convergence_to_truth(f64::cos, 3);
+        for frame in &frames[1..] {
+            for (row, cells) in frame.iter().zip(&frames[0]) {
+                for (a, b) in row.iter().zip(cells) {
+                    assert!((a - b).abs() < 1e-12);
+                }
+            }
+        }
+    }
+}
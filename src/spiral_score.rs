@@ -10,6 +10,10 @@
 #[cfg(not(target_arch = "wasm32"))]
 use std::collections::HashMap;
 
+use crate::TrajectoryPoint;
+use crate::time_spiral::TimeSpiral;
+use core::f32::consts::TAU;
+
 /// The three levels of hash freedom
 #[derive(Clone, Copy, Debug)]
 pub enum HashFreedom {
@@ -66,6 +70,25 @@ impl SpiralScore {
         }
     }
     
+    /// The same four voices in equal temperament above `base`:
+    /// root, perfect fifth (+7), major third (+4), minor seventh (+10)
+    pub fn equal_temperament_quartet(base: f32) -> Self {
+        let voice = |semitones: i32, symbol: u32| {
+            Glyph::tuned(Glyph::equal_temperament_frequency(base, semitones), symbol)
+        };
+        
+        SpiralScore {
+            musicians: [
+                voice(0, 0x1F300),  // 🌀 root
+                voice(7, 0x1F4AB),  // 💫 perfect fifth
+                voice(4, 0x1F52E),  // 🔮 major third
+                voice(10, 0x2764),  // ❤️ minor seventh
+            ],
+            notes: Vec::new(),
+            future_shadow: 0.618,
+        }
+    }
+    
     /// Add a note to the spiral
     pub fn add_note(&mut self, musician_idx: usize, time: SpiralTime, amplitude: f32) {
         if musician_idx < 4 {
@@ -118,6 +141,729 @@ impl SpiralScore {
         let interference = (angle_diff.cos() * radius_diff.exp() * (1.0 - layer_harmony)).abs();
         interference.min(1.0)
     }
+    
+    /// Amplitude profile along the radius (time) axis
+    pub fn harmonic_envelope(&self, time_resolution: usize) -> Vec<f32> {
+        let mut envelope = vec![0.0f32; time_resolution];
+        
+        let max_radius = self.notes.iter()
+            .map(|note| note.time.radius)
+            .fold(0.0f32, f32::max);
+        
+        if time_resolution == 0 || max_radius <= 0.0 {
+            return envelope;
+        }
+        
+        for note in &self.notes {
+            // The outermost note belongs to the last bin
+            let bin = ((note.time.radius.max(0.0) / max_radius) * time_resolution as f32) as usize;
+            let bin = bin.min(time_resolution - 1);
+        
+            // Weighted by the fundamental harmonic
+            envelope[bin] += note.amplitude * note.glyph.harmonics[0];
+        }
+        
+        envelope
+    }
+    
+    /// The loudest moment of the envelope: (bin, amplitude)
+    pub fn harmonic_envelope_peak(&self, time_resolution: usize) -> (usize, f32) {
+        self.harmonic_envelope(time_resolution)
+            .into_iter()
+            .enumerate()
+            .fold((0, 0.0), |peak, (bin, amplitude)| {
+                if amplitude > peak.1 { (bin, amplitude) } else { peak }
+            })
+    }
+    
+    /// Root-mean-square amplitude across all bins
+    pub fn harmonic_envelope_rms(&self, time_resolution: usize) -> f32 {
+        if time_resolution == 0 {
+            return 0.0;
+        }
+        
+        let envelope = self.harmonic_envelope(time_resolution);
+        let mean_square = envelope.iter().map(|a| a * a).sum::<f32>() / time_resolution as f32;
+        mean_square.sqrt()
+    }
+    
+    /// Amplitude-weighted mean frequency of all notes (0 for a silent score)
+    pub fn spectral_centroid(&self) -> f32 {
+        let total: f32 = self.notes.iter().map(|note| note.amplitude).sum();
+        if total == 0.0 {
+            return 0.0;
+        }
+        
+        self.notes.iter().map(|note| note.glyph.frequency * note.amplitude).sum::<f32>() / total
+    }
+    
+    /// Amplitude-weighted standard deviation of frequency around the centroid
+    pub fn spectral_bandwidth(&self) -> f32 {
+        let total: f32 = self.notes.iter().map(|note| note.amplitude).sum();
+        if total == 0.0 {
+            return 0.0;
+        }
+        
+        let centroid = self.spectral_centroid();
+        let variance = self.notes.iter()
+            .map(|note| (note.glyph.frequency - centroid).powi(2) * note.amplitude)
+            .sum::<f32>() / total;
+        variance.max(0.0).sqrt()
+    }
+    
+    /// Lowest frequency at or below which `threshold` (0-1) of the spectral
+    /// energy (Σ amplitude²) is contained
+    pub fn spectral_rolloff(&self, threshold: f32) -> f32 {
+        let mut spectrum: Vec<(f32, f32)> = self.notes.iter()
+            .map(|note| (note.glyph.frequency, note.amplitude * note.amplitude))
+            .collect();
+        spectrum.sort_by(|a, b| a.0.total_cmp(&b.0));
+        
+        let total: f32 = spectrum.iter().map(|&(_, energy)| energy).sum();
+        let target = total * threshold.clamp(0.0, 1.0);
+        
+        let mut accumulated = 0.0;
+        for &(frequency, energy) in &spectrum {
+            accumulated += energy;
+            if accumulated >= target {
+                return frequency;
+            }
+        }
+        spectrum.last().map_or(0.0, |&(frequency, _)| frequency)
+    }
+    
+    /// Note counts over spiral space: rows are radius bins, columns angle bins
+    pub fn density_map(&self, grid_size: usize) -> Vec<Vec<usize>> {
+        let mut map = vec![vec![0usize; grid_size]; grid_size];
+        if grid_size == 0 {
+            return map;
+        }
+        
+        // The radius axis spans only the part of the spiral that is played
+        let min_radius = self.notes.iter()
+            .map(|note| note.time.radius)
+            .fold(f32::INFINITY, f32::min);
+        let max_radius = self.notes.iter()
+            .map(|note| note.time.radius)
+            .fold(f32::NEG_INFINITY, f32::max);
+        let span = max_radius - min_radius;
+        
+        for note in &self.notes {
+            let radius_bin = if span > 0.0 {
+                ((note.time.radius - min_radius) / span * grid_size as f32) as usize
+            } else {
+                0
+            };
+            let angle_bin = (note.time.angle.rem_euclid(TAU) / TAU * grid_size as f32) as usize;
+            
+            map[radius_bin.min(grid_size - 1)][angle_bin.min(grid_size - 1)] += 1;
+        }
+        
+        map
+    }
+    
+    /// The most crowded cell: (radius_bin, angle_bin, count)
+    pub fn density_peak(&self, grid_size: usize) -> (usize, usize, usize) {
+        let mut peak = (0, 0, 0);
+        for (r, row) in self.density_map(grid_size).iter().enumerate() {
+            for (a, &count) in row.iter().enumerate() {
+                if count > peak.2 {
+                    peak = (r, a, count);
+                }
+            }
+        }
+        peak
+    }
+    
+    /// 1 - Gini coefficient of the density map
+    /// 1.0 = notes spread evenly, 0.0 = every note in a single cell
+    pub fn uniformity_score(&self, grid_size: usize) -> f32 {
+        let counts: Vec<f32> = self.density_map(grid_size)
+            .into_iter()
+            .flatten()
+            .map(|c| c as f32)
+            .collect();
+        
+        let n = counts.len() as f32;
+        let total: f32 = counts.iter().sum();
+        if counts.len() < 2 || total == 0.0 {
+            return 0.0;
+        }
+        
+        let mut differences = 0.0;
+        for a in &counts {
+            for b in &counts {
+                differences += (a - b).abs();
+            }
+        }
+        
+        // Gini scaled by n/(n-1) so full concentration reaches exactly 1
+        let gini = differences / (2.0 * n * total) * n / (n - 1.0);
+        (1.0 - gini).clamp(0.0, 1.0)
+    }
+    
+    /// Keep only notes whose amplitude lies within [min, max]
+    pub fn filter_by_harmonic_range(&self, min: f32, max: f32) -> SpiralScore {
+        self.filtered(|note| note.amplitude >= min && note.amplitude <= max)
+    }
+    
+    /// Keep only notes from one spiral arm
+    pub fn filter_by_layer(&self, layer: u8) -> SpiralScore {
+        self.filtered(|note| note.time.layer == layer)
+    }
+    
+    /// Keep only notes played by the musician with this glyph symbol
+    pub fn filter_by_glyph_symbol(&self, symbol: u32) -> SpiralScore {
+        self.filtered(|note| note.glyph.symbol == symbol)
+    }
+    
+    /// Where does a motif recur? Starting indices in time order,
+    /// matched with dynamic time warping (mean DTW cost ≤ tolerance)
+    pub fn detect_motif(&self, pattern: &[f32], tolerance: f32) -> Vec<usize> {
+        let amplitudes = self.time_ordered_amplitudes();
+        if pattern.is_empty() || amplitudes.len() < pattern.len() {
+            return Vec::new();
+        }
+        
+        amplitudes.windows(pattern.len())
+            .enumerate()
+            .filter(|(_, window)| dtw_distance(window, pattern) / pattern.len() as f32 <= tolerance)
+            .map(|(start, _)| start)
+            .collect()
+    }
+    
+    /// How many times a motif recurs
+    pub fn motif_count(&self, pattern: &[f32], tolerance: f32) -> usize {
+        self.detect_motif(pattern, tolerance).len()
+    }
+    
+    /// The motif of the given length that recurs most often
+    pub fn most_frequent_motif(&self, motif_length: usize, tolerance: f32) -> Option<Vec<f32>> {
+        let amplitudes = self.time_ordered_amplitudes();
+        if motif_length == 0 || amplitudes.len() < motif_length {
+            return None;
+        }
+        
+        let mut best: Option<(&[f32], usize)> = None;
+        for candidate in amplitudes.windows(motif_length) {
+            let count = self.motif_count(candidate, tolerance);
+            if best.is_none_or(|(_, best_count)| count > best_count) {
+                best = Some((candidate, count));
+            }
+        }
+        
+        best.map(|(motif, _)| motif.to_vec())
+    }
+    
+    /// Notes that are overtones of `fundamental`: (note_index, harmonic_order)
+    /// where the glyph frequency lies within `tolerance` Hz of order × fundamental
+    pub fn detect_harmonics(&self, fundamental: f32, tolerance: f32) -> Vec<(usize, u32)> {
+        if fundamental <= 0.0 {
+            return Vec::new();
+        }
+        
+        self.notes.iter()
+            .enumerate()
+            .filter_map(|(i, note)| {
+                let order = (note.glyph.frequency / fundamental).round();
+                let in_tune = (note.glyph.frequency - fundamental * order).abs() <= tolerance;
+                if order >= 1.0 && in_tune { Some((i, order as u32)) } else { None }
+            })
+            .collect()
+    }
+    
+    /// The overtone series 1..=max_harmonic: which note (if any) plays each partial
+    pub fn detect_partials_series(
+        &self,
+        fundamental: f32,
+        max_harmonic: u32,
+        tolerance: f32
+    ) -> Vec<Option<usize>> {
+        let mut series = vec![None; max_harmonic as usize];
+        
+        for (index, order) in self.detect_harmonics(fundamental, tolerance) {
+            if let Some(slot) = series.get_mut(order as usize - 1) {
+                // The first note to sound a partial claims it
+                slot.get_or_insert(index);
+            }
+        }
+        
+        series
+    }
+    
+    /// Does musician A play `ratio.0` beats for every `ratio.1` of musician B?
+    /// Compares the median radial spacing of their notes within `tolerance`
+    pub fn polyrhythm_check(
+        &self,
+        musician_a: usize,
+        musician_b: usize,
+        ratio: (u32, u32),
+        tolerance: f32
+    ) -> bool {
+        if ratio.1 == 0 {
+            return false;
+        }
+        
+        match self.beat_ratio(musician_a, musician_b) {
+            Some(measured) => (measured - ratio.0 as f32 / ratio.1 as f32).abs() <= tolerance,
+            None => false,
+        }
+    }
+    
+    /// The simple ratio p:q (p, q ≤ 7) closest to the two musicians' rhythm
+    pub fn detect_polyrhythm(&self, musician_a: usize, musician_b: usize) -> Option<(u32, u32)> {
+        let measured = self.beat_ratio(musician_a, musician_b)?;
+        
+        let mut best: Option<((u32, u32), f32)> = None;
+        for p in 1..=7u32 {
+            for q in 1..=7u32 {
+                // Reduced forms come first, so 2:4 never displaces 1:2
+                let error = (measured - p as f32 / q as f32).abs();
+                if best.is_none_or(|(_, best_error)| error < best_error) {
+                    best = Some(((p, q), error));
+                }
+            }
+        }
+        
+        best.map(|(ratio, _)| ratio)
+    }
+    
+    /// Do the gaps between consecutive notes grow like the Fibonacci numbers?
+    /// Gaps are normalized to the smallest one and must match (within
+    /// `tolerance`) a run of at least 4 consecutive Fibonacci numbers,
+    /// themselves normalized to the run's first number
+    pub fn detect_fibonacci_rhythm(&self, tolerance: f32) -> bool {
+        let gaps = self.rhythm_gaps();
+        if gaps.len() < 4 {
+            return false;
+        }
+        
+        let smallest = gaps.iter().fold(f32::MAX, |a, &b| a.min(b));
+        let fibonacci = fibonacci_numbers(gaps.len() + 24);
+        
+        (0..=fibonacci.len() - gaps.len()).any(|start| {
+            let run = &fibonacci[start..start + gaps.len()];
+            gaps.iter().zip(run.iter())
+                .all(|(&gap, &number)| (gap / smallest - number / run[0]).abs() <= tolerance)
+        })
+    }
+    
+    /// Longest stretch of consecutive gaps growing like a Fibonacci run
+    /// (each gap within 5% of its Fibonacci proportion to the stretch's
+    /// first gap), as a share of all gaps - 1.0 = pure Fibonacci rhythm
+    pub fn fibonacci_rhythmic_score(&self) -> f32 {
+        let gaps = self.rhythm_gaps();
+        if gaps.len() < 2 {
+            return 0.0;
+        }
+        
+        let fibonacci = fibonacci_numbers(gaps.len() + 24);
+        let mut longest = 0;
+        
+        for first in 0..gaps.len() {
+            for start in 0..=fibonacci.len() - gaps.len() {
+                let length = gaps[first..].iter()
+                    .zip(fibonacci[start..].iter())
+                    .take_while(|&(&gap, &number)| {
+                        let expected = gaps[first] * number / fibonacci[start];
+                        (gap - expected).abs() <= 0.05 * expected
+                    })
+                    .count();
+                longest = longest.max(length);
+            }
+        }
+        
+        longest as f32 / gaps.len() as f32
+    }
+    
+    /// Beats of A per beat of B: median spacing of B over median spacing of A
+    fn beat_ratio(&self, musician_a: usize, musician_b: usize) -> Option<f32> {
+        let spacing_a = self.median_spacing(musician_a)?;
+        let spacing_b = self.median_spacing(musician_b)?;
+        Some(spacing_b / spacing_a)
+    }
+    
+    /// Median radial gap between a musician's consecutive notes
+    fn median_spacing(&self, musician: usize) -> Option<f32> {
+        let symbol = self.musicians.get(musician)?.symbol;
+        let mut radii: Vec<f32> = self.notes.iter()
+            .filter(|note| note.glyph.symbol == symbol)
+            .map(|note| note.time.radius)
+            .collect();
+        radii.sort_by(|a, b| a.total_cmp(b));
+        
+        let mut gaps: Vec<f32> = radii.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        if gaps.is_empty() {
+            return None;
+        }
+        gaps.sort_by(|a, b| a.total_cmp(b));
+        
+        let mid = gaps.len() / 2;
+        let median = if gaps.len().is_multiple_of(2) { (gaps[mid - 1] + gaps[mid]) / 2.0 } else { gaps[mid] };
+        if median > 0.0 { Some(median) } else { None }
+    }
+    
+    /// Radial gaps between consecutive notes of the whole score
+    /// (notes sounding together share a beat, so zero gaps are skipped)
+    fn rhythm_gaps(&self) -> Vec<f32> {
+        let mut radii: Vec<f32> = self.notes.iter().map(|note| note.time.radius).collect();
+        radii.sort_by(|a, b| a.total_cmp(b));
+        
+        radii.windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .filter(|&gap| gap > 0.0)
+            .collect()
+    }
+    
+    /// Snap every note onto the beat grid at `bpm`
+    pub fn quantize_to_grid(&mut self, bpm: f32) {
+        for note in self.notes.iter_mut() {
+            note.time = note.time.quantize_to_beat(bpm);
+        }
+    }
+    
+    /// Engrave the score as a LilyPond document, one staff per musician
+    /// Radius → duration (inner spiral = long notes), frequency → nearest
+    /// pitch with A4 = 432 Hz, amplitude → dynamic marking
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn export_as_lilypond(&self) -> String {
+        use std::fmt::Write as _;
+        
+        let min_radius = self.notes.iter().map(|n| n.time.radius).fold(f32::INFINITY, f32::min);
+        let max_radius = self.notes.iter().map(|n| n.time.radius).fold(f32::NEG_INFINITY, f32::max);
+        let span = max_radius - min_radius;
+        
+        let mut ly = String::new();
+        ly.push_str("\\version \"2.24.0\"\n\n\\score {\n  \\new GrandStaff <<\n");
+        
+        for musician in &self.musicians {
+            let mut notes: Vec<&SpiralNote> = self.notes.iter()
+                .filter(|note| note.glyph.symbol == musician.symbol)
+                .collect();
+            notes.sort_by(|a, b| a.time.radius.total_cmp(&b.time.radius));
+            
+            let _ = write!(ly, "    \\new Staff {{");
+            if notes.is_empty() {
+                ly.push_str(" r1");  // A silent musician still holds a staff
+            }
+            
+            let mut dynamic = "";
+            for note in notes {
+                let position = if span > 0.0 { (note.time.radius - min_radius) / span } else { 0.0 };
+                let duration = match position {
+                    p if p < 0.25 => 1,
+                    p if p < 0.5 => 2,
+                    p if p < 0.75 => 4,
+                    _ => 8,
+                };
+                let _ = write!(ly, " {}{}", lilypond_pitch(note.glyph.frequency), duration);
+                
+                let marking = lilypond_dynamic(note.amplitude);
+                if marking != dynamic {
+                    let _ = write!(ly, "\\{}", marking);
+                    dynamic = marking;
+                }
+            }
+            ly.push_str(" }\n");
+        }
+        
+        ly.push_str("  >>\n}\n");
+        ly
+    }
+    
+    /// Split every note louder than `min_amplitude_threshold` in two,
+    /// spreading it over [radius, radius + 1]
+    pub fn subdivide_all_notes(&mut self, min_amplitude_threshold: f32) {
+        let mut subdivided = Vec::with_capacity(self.notes.len() * 2);
+        
+        for note in self.notes.drain(..) {
+            if note.amplitude > min_amplitude_threshold {
+                let (first, second) = note.split_at_midpoint(note.time.radius, note.time.radius + 1.0);
+                subdivided.push(first);
+                subdivided.push(second);
+            } else {
+                subdivided.push(note);
+            }
+        }
+        
+        self.notes = subdivided;
+    }
+    
+    /// The score reduced to its musical content: musicians sorted by
+    /// frequency (layers follow them), frequencies relative to the lowest
+    /// musician, radii scaled into [0, 1], loudest note at amplitude 1.0,
+    /// and notes ordered by radius, layer and angle
+    pub fn canonical_form(&self) -> SpiralScore {
+        let mut order = [0usize, 1, 2, 3];
+        order.sort_by(|&a, &b| self.musicians[a].frequency.total_cmp(&self.musicians[b].frequency));
+        
+        // Where each old musician index ends up
+        let mut new_index = [0u8; 4];
+        for (new, &old) in order.iter().enumerate() {
+            new_index[old] = new as u8;
+        }
+        
+        let root = self.musicians[order[0]].frequency;
+        let pitch = |frequency: f32| if root > 0.0 { frequency / root } else { frequency };
+        let max_radius = self.notes.iter().map(|n| n.time.radius).fold(0.0f32, f32::max);
+        let max_amplitude = self.notes.iter().map(|n| n.amplitude).fold(0.0f32, f32::max);
+        
+        let musicians: [Glyph; 4] = core::array::from_fn(|new| {
+            let mut glyph = self.musicians[order[new]].clone();
+            glyph.frequency = pitch(glyph.frequency);
+            glyph
+        });
+        
+        let mut notes: Vec<SpiralNote> = self.notes.iter()
+            .map(|note| {
+                let mut canonical = note.clone();
+                canonical.glyph.frequency = pitch(note.glyph.frequency);
+                if max_radius > 0.0 {
+                    canonical.time.radius /= max_radius;
+                }
+                if max_amplitude > 0.0 {
+                    canonical.amplitude /= max_amplitude;
+                }
+                if let Some(&layer) = new_index.get(note.time.layer as usize) {
+                    canonical.time.layer = layer;
+                }
+                canonical
+            })
+            .collect();
+        notes.sort_by(|a, b| {
+            a.time.radius.total_cmp(&b.time.radius)
+                .then(a.time.layer.cmp(&b.time.layer))
+                .then(a.time.angle.total_cmp(&b.time.angle))
+        });
+        
+        SpiralScore {
+            musicians,
+            notes,
+            future_shadow: self.future_shadow,
+        }
+    }
+    
+    /// Same music once both scores are in canonical form (floats within epsilon)
+    pub fn is_canonical_equivalent(&self, other: &SpiralScore, epsilon: f32) -> bool {
+        let (a, b) = (self.canonical_form(), other.canonical_form());
+        let close = |x: f32, y: f32| (x - y).abs() <= epsilon;
+        let same_glyph = |g: &Glyph, h: &Glyph| {
+            g.symbol == h.symbol
+                && close(g.frequency, h.frequency)
+                && close(g.intent, h.intent)
+                && g.harmonics.iter().zip(h.harmonics.iter()).all(|(x, y)| close(*x, *y))
+        };
+        
+        a.musicians.iter().zip(b.musicians.iter()).all(|(g, h)| same_glyph(g, h))
+            && a.notes.len() == b.notes.len()
+            && a.notes.iter().zip(b.notes.iter()).all(|(m, n)| {
+                m.time.layer == n.time.layer
+                    && close(m.time.radius, n.time.radius)
+                    && close(m.time.angle, n.time.angle)
+                    && close(m.amplitude, n.amplitude)
+                    && close(m.phase, n.phase)
+                    && same_glyph(&m.glyph, &n.glyph)
+            })
+    }
+    
+    /// Note amplitudes in the order they sound (inner spiral first)
+    fn time_ordered_amplitudes(&self) -> Vec<f32> {
+        let mut ordered: Vec<&SpiralNote> = self.notes.iter().collect();
+        ordered.sort_by(|a, b| {
+            (a.time.radius, a.time.angle)
+                .partial_cmp(&(b.time.radius, b.time.angle))
+                .unwrap_or(core::cmp::Ordering::Equal)
+        });
+        ordered.iter().map(|note| note.amplitude).collect()
+    }
+    
+    /// Same quartet and vision, fewer notes
+    fn filtered(&self, keep: impl Fn(&SpiralNote) -> bool) -> SpiralScore {
+        SpiralScore {
+            musicians: self.musicians.clone(),
+            notes: self.notes.iter().filter(|note| keep(note)).cloned().collect(),
+            future_shadow: self.future_shadow,
+        }
+    }
+}
+
+/// The first `count` Fibonacci numbers: 1, 1, 2, 3, 5, 8, ...
+fn fibonacci_numbers(count: usize) -> Vec<f32> {
+    let mut numbers = Vec::with_capacity(count);
+    let (mut a, mut b) = (1.0f32, 1.0f32);
+    for _ in 0..count {
+        numbers.push(a);
+        (a, b) = (b, a + b);
+    }
+    numbers
+}
+
+/// Nearest LilyPond pitch (absolute octaves, c' = middle C) with A4 = 432 Hz
+#[cfg(not(target_arch = "wasm32"))]
+fn lilypond_pitch(frequency: f32) -> String {
+    const NAMES: [&str; 12] = ["c", "cis", "d", "dis", "e", "f", "fis", "g", "gis", "a", "ais", "b"];
+    
+    if frequency <= 0.0 {
+        return String::from("r");  // Silence
+    }
+    
+    // Semitones above C4 (A4 is nine semitones up)
+    let semitones = (12.0 * (frequency / 432.0).log2()).round() as i32 + 9;
+    let name = NAMES[semitones.rem_euclid(12) as usize];
+    let octave_marks = semitones.div_euclid(12) + 1;  // c' is octave 4
+    
+    let mark = if octave_marks >= 0 { "'" } else { "," };
+    format!("{}{}", name, mark.repeat(octave_marks.unsigned_abs() as usize))
+}
+
+/// Dynamic marking for a note amplitude
+#[cfg(not(target_arch = "wasm32"))]
+fn lilypond_dynamic(amplitude: f32) -> &'static str {
+    match amplitude {
+        a if a < 0.2 => "pp",
+        a if a < 0.4 => "p",
+        a if a < 0.6 => "mp",
+        a if a < 0.8 => "mf",
+        a if a < 1.0 => "f",
+        _ => "ff",
+    }
+}
+
+impl SpiralTime {
+    /// Anchor the spiral to the calendar: seconds since the Unix epoch
+    pub fn to_unix_timestamp(&self, epoch_radius: f32, radius_scale: f32) -> f64 {
+        (self.radius as f64 - epoch_radius as f64) * radius_scale as f64
+    }
+    
+    /// Place a Unix timestamp on the spiral
+    /// The angle is the time of day, the arm is the day in a 4-day cycle
+    pub fn from_unix_timestamp(ts: f64, epoch_radius: f32, radius_scale: f32) -> SpiralTime {
+        const DAY: f64 = 86400.0;
+        
+        SpiralTime {
+            radius: (epoch_radius as f64 + ts / radius_scale as f64) as f32,
+            angle: (2.0 * core::f64::consts::PI * ts.rem_euclid(DAY) / DAY) as f32,
+            layer: ((ts / DAY).floor() as i64).rem_euclid(4) as u8,
+        }
+    }
+    
+    /// Snap to the nearest beat at `bpm` on the golden time spiral, the
+    /// clock `TimeSpiral::synchronize_with_beats` places beats on
+    /// (moves at most half a beat of linear time)
+    pub fn quantize_to_beat(&self, bpm: f32) -> SpiralTime {
+        TimeSpiral::golden().quantize_to_beat(self, bpm)
+    }
+}
+
+impl SpiralNote {
+    /// Render the note into seven-layer trajectory space
+    pub fn render_to_trajectory(&self) -> TrajectoryPoint {
+        TrajectoryPoint {
+            eigenvalue: self.amplitude * self.glyph.harmonics[0],
+            eigen_trajectory: self.amplitude * self.glyph.harmonics[1],
+            activation: self.amplitude * self.glyph.frequency / 1000.0,
+            attention: self.time.radius / 10.0,
+            intent: self.time.angle / TAU,
+            meta: self.glyph.intent,
+            void: 1.0 - self.amplitude,
+        }
+    }
+    
+    /// Halve a note spanning [start_radius, end_radius] into two notes at
+    /// the start and the midpoint, each carrying half the amplitude
+    pub fn split_at_midpoint(&self, start_radius: f32, end_radius: f32) -> (SpiralNote, SpiralNote) {
+        let half = |radius: f32| {
+            let mut note = self.clone();
+            note.time.radius = radius;
+            note.amplitude = self.amplitude * 0.5;
+            note
+        };
+        
+        (half(start_radius), half((start_radius + end_radius) / 2.0))
+    }
+}
+
+impl Glyph {
+    /// Twelve-tone equal temperament: each semitone is a factor of 2^(1/12)
+    pub fn equal_temperament_frequency(base: f32, semitones: i32) -> f32 {
+        base * 2.0_f32.powf(semitones as f32 / 12.0)
+    }
+    
+    /// A glyph tuned `semitone` steps away from A4 = 432 Hz,
+    /// singing the first seven overtones with 1/n strength
+    pub fn from_semitone(semitone: i32, symbol: u32) -> Glyph {
+        Glyph::tuned(Glyph::equal_temperament_frequency(432.0, semitone), symbol)
+    }
+    
+    /// A glyph at an exact frequency with the natural overtone series
+    fn tuned(frequency: f32, symbol: u32) -> Glyph {
+        let mut harmonics = [0.0f32; 7];
+        for (i, h) in harmonics.iter_mut().enumerate() {
+            *h = 1.0 / (i + 1) as f32;
+        }
+        
+        Glyph { symbol, frequency, harmonics, intent: 1.0 }
+    }
+    
+    /// Two glyphs meet and a child glyph is born from their harmonic mean
+    pub fn harmonize_with(&self, other: &Glyph) -> Glyph {
+        let mut harmonics = [0.0f32; 7];
+        let pairs = self.harmonics.iter().zip(other.harmonics.iter());
+        for (h, (a, b)) in harmonics.iter_mut().zip(pairs) {
+            *h = harmonic_mean(*a, *b);
+        }
+        
+        // The more intentional glyph gives its symbol
+        let symbol = if self.intent >= other.intent { self.symbol } else { other.symbol };
+        
+        Glyph {
+            symbol,
+            frequency: harmonic_mean(self.frequency, other.frequency),
+            harmonics,
+            intent: (self.intent * other.intent).max(0.0).sqrt(),
+        }
+    }
+    
+    /// How far the frequency ratio is from the nearest simple interval
+    pub fn dissonance_with(&self, other: &Glyph) -> f32 {
+        let low = self.frequency.min(other.frequency);
+        let high = self.frequency.max(other.frequency);
+        if low <= 0.0 {
+            return 1.0; // Silence has no interval
+        }
+        
+        let ratio = high / low;
+        // Unison, octave, fifth, fourth, major third
+        [1.0, 2.0, 1.5, 4.0 / 3.0, 1.25]
+            .iter()
+            .map(|simple| (ratio - simple).abs())
+            .fold(f32::MAX, f32::min)
+    }
+}
+
+/// Dynamic time warping cost between two amplitude sequences
+fn dtw_distance(a: &[f32], b: &[f32]) -> f32 {
+    let (n, m) = (a.len(), b.len());
+    let mut cost = vec![vec![f32::INFINITY; m + 1]; n + 1];
+    cost[0][0] = 0.0;
+    
+    for i in 1..=n {
+        for j in 1..=m {
+            let step = (a[i - 1] - b[j - 1]).abs();
+            cost[i][j] = step + cost[i - 1][j].min(cost[i][j - 1]).min(cost[i - 1][j - 1]);
+        }
+    }
+    
+    cost[n][m]
+}
+
+/// Harmonic mean of two values (zero absorbs everything)
+fn harmonic_mean(a: f32, b: f32) -> f32 {
+    if a == 0.0 || b == 0.0 || a + b == 0.0 {
+        0.0
+    } else {
+        2.0 * a * b / (a + b)
+    }
 }
 
 /// Convert CID to glyphHash (maximum freedom)
@@ -150,6 +896,36 @@ pub extern "C" fn cid_to_glyph(cid_bytes: &[u8; 32]) -> Glyph {
     }
 }
 
+/// Convert CID to a trajectory point: the same seven byte groups as
+/// `cid_to_glyph`, each landing on one layer (eigenvalue first)
+#[no_mangle]
+pub extern "C" fn cid_to_trajectory(cid_bytes: &[u8; 32]) -> TrajectoryPoint {
+    let mut layers = [0.0f32; 7];
+    for (i, layer) in layers.iter_mut().enumerate() {
+        let byte_group = &cid_bytes[i*4..(i+1)*4];
+        let value = u32::from_le_bytes([
+            byte_group[0], byte_group[1],
+            byte_group[2], byte_group[3]
+        ]);
+        *layer = (value as f32) / (u32::MAX as f32);
+    }
+    
+    TrajectoryPoint::from_array(&layers)
+}
+
+/// Fold a trajectory point back into CID-shaped bytes (layers clamped to
+/// [0, 1], the last 4 bytes left as zero). An f32 layer keeps 24 bits, so a
+/// byte group survives the round trip exactly when it fits in 24 bits
+pub fn trajectory_to_pseudo_cid(point: &TrajectoryPoint) -> [u8; 32] {
+    let mut cid = [0u8; 32];
+    for (i, layer) in point.to_array().iter().enumerate() {
+        // Undo the exact power-of-two scale of `cid_to_trajectory`
+        let value = (layer.clamp(0.0, 1.0) * u32::MAX as f32) as u32;
+        cid[i*4..(i+1)*4].copy_from_slice(&value.to_le_bytes());
+    }
+    cid
+}
+
 /// The hierarchy of freedom
 #[no_mangle]
 pub extern "C" fn hash_freedom_level(hash_type: u8) -> f32 {
@@ -211,4 +987,380 @@ impl Clone for Glyph {
             intent: self.intent,
         }
     }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Clone for SpiralTime {
+    fn clone(&self) -> Self {
+        SpiralTime {
+            radius: self.radius,
+            angle: self.angle,
+            layer: self.layer,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Clone for SpiralNote {
+    fn clone(&self) -> Self {
+        SpiralNote {
+            time: self.time.clone(),
+            glyph: self.glyph.clone(),
+            amplitude: self.amplitude,
+            phase: self.phase,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn empty_score_has_a_silent_envelope() {
+        let score = SpiralScore::quartet();
+        
+        assert_eq!(score.harmonic_envelope(8), vec![0.0; 8]);
+        assert_eq!(score.harmonic_envelope_peak(8), (0, 0.0));
+        assert_eq!(score.harmonic_envelope_rms(8), 0.0);
+    }
+    
+    #[test]
+    fn notes_land_in_their_radius_bin() {
+        let mut score = SpiralScore::quartet();
+        score.add_note(0, SpiralTime { radius: 1.0, angle: 0.0, layer: 0 }, 0.5);
+        score.add_note(1, SpiralTime { radius: 4.0, angle: 0.0, layer: 1 }, 2.0);
+        
+        assert_eq!(score.harmonic_envelope(4), vec![0.0, 0.5, 0.0, 2.0]);
+        assert_eq!(score.harmonic_envelope_peak(4), (3, 2.0));
+    }
+    
+    #[test]
+    fn harmonizing_identical_glyphs_returns_the_same_glyph() {
+        let glyph = Glyph {
+            symbol: 0x1F52E,
+            frequency: 639.0,
+            harmonics: [1.0, 0.5, 0.0, 0.25, 2.0, 0.75, 0.125],
+            intent: 0.8,
+        };
+        let child = glyph.harmonize_with(&glyph);
+        
+        assert_eq!(child.symbol, glyph.symbol);
+        assert!((child.frequency - glyph.frequency).abs() < 1e-3);
+        for (h, g) in child.harmonics.iter().zip(glyph.harmonics.iter()) {
+            assert!((h - g).abs() < 1e-6);
+        }
+        assert!((child.intent - glyph.intent).abs() < 1e-6);
+        assert_eq!(glyph.dissonance_with(&glyph), 0.0);
+    }
+    
+    #[test]
+    fn dissonance_measures_distance_to_simple_intervals() {
+        let tuned = |frequency: f32| Glyph { symbol: 0, frequency, harmonics: [1.0; 7], intent: 1.0 };
+        
+        assert!(tuned(440.0).dissonance_with(&tuned(660.0)).abs() < 1e-6);
+        assert!((tuned(400.0).dissonance_with(&tuned(700.0)) - 0.25).abs() < 1e-6);
+    }
+    
+    /// Eight notes: each musician on its own arm, amplitudes 0.1 … 0.8
+    fn known_score() -> SpiralScore {
+        let mut score = SpiralScore::quartet();
+        for i in 0..8 {
+            let time = SpiralTime { radius: i as f32, angle: 0.0, layer: (i % 4) as u8 };
+            score.add_note(i % 4, time, (i + 1) as f32 / 10.0);
+        }
+        score
+    }
+    
+    #[test]
+    fn filters_keep_the_expected_notes() {
+        let score = known_score();
+        
+        assert_eq!(score.filter_by_harmonic_range(0.25, 0.55).notes.len(), 3);
+        assert_eq!(score.filter_by_layer(2).notes.len(), 2);
+        assert_eq!(score.filter_by_layer(7).notes.len(), 0);
+        assert_eq!(score.filter_by_glyph_symbol(0x1F4AB).notes.len(), 2);
+        // 🌀 and ❤️ share 432 Hz but not their symbol
+        assert_eq!(score.filter_by_glyph_symbol(0x2764).notes.len(), 2);
+        
+        let filtered = score.filter_by_layer(0);
+        assert_eq!(filtered.future_shadow, score.future_shadow);
+        assert_eq!(filtered.musicians[3].symbol, 0x2764);
+    }
+    
+    #[test]
+    fn note_survives_a_trip_through_trajectory_space() {
+        let mut score = known_score();
+        score.notes[5].glyph.harmonics[0] = 0.8;
+        score.notes[5].glyph.harmonics[1] = 0.3;
+        score.notes[5].glyph.intent = 0.7;
+        let note = &score.notes[5];
+        
+        let point = note.render_to_trajectory();
+        let back = point.to_spiral_note(note.time.clone(), &score.musicians[1]);
+        
+        let close = |a: f32, b: f32| (a - b).abs() <= 0.01 * a.abs().max(b.abs());
+        assert!(close(back.amplitude, note.amplitude));
+        assert!(close(back.glyph.frequency, note.glyph.frequency));
+        assert!(close(back.glyph.intent, note.glyph.intent));
+        for (a, b) in back.glyph.harmonics.iter().zip(note.glyph.harmonics.iter()).take(2) {
+            assert!(close(*a, *b));
+        }
+        for (a, b) in back.render_to_trajectory().to_array().iter().zip(point.to_array().iter()) {
+            assert!(close(*a, *b));
+        }
+    }
+    
+    /// The motif 0.9, 0.1, 0.5 three times, with other notes in between,
+    /// written into the score backwards in time
+    fn motif_score() -> SpiralScore {
+        let amplitudes = [0.9, 0.1, 0.5, 0.3, 0.9, 0.1, 0.5, 0.7, 0.9, 0.1, 0.5];
+        let mut score = SpiralScore::quartet();
+        for (i, &amplitude) in amplitudes.iter().enumerate().rev() {
+            let time = SpiralTime { radius: i as f32, angle: 0.0, layer: (i % 4) as u8 };
+            score.add_note(i % 4, time, amplitude);
+        }
+        score
+    }
+    
+    #[test]
+    fn repeated_motif_is_found_in_time_order() {
+        let score = motif_score();
+        
+        assert_eq!(score.detect_motif(&[0.9, 0.1, 0.5], 0.01), vec![0, 4, 8]);
+        assert_eq!(score.motif_count(&[0.9, 0.1, 0.5], 0.01), 3);
+        assert_eq!(score.motif_count(&[0.1, 0.9], 0.01), 0);
+        assert!(score.detect_motif(&[], 0.01).is_empty());
+    }
+    
+    #[test]
+    fn most_frequent_motif_is_the_repeated_one() {
+        let score = motif_score();
+        
+        assert_eq!(score.most_frequent_motif(3, 0.01), Some(vec![0.9, 0.1, 0.5]));
+        assert_eq!(score.most_frequent_motif(12, 0.01), None);
+    }
+    
+    #[test]
+    fn unix_timestamps_round_trip_within_a_second() {
+        // Radius counted in minutes from an epoch at radius 10
+        for ts in [0.0, 59.0, 271_545.0, 1_000_000.0, -3_600.0] {
+            let time = SpiralTime::from_unix_timestamp(ts, 10.0, 60.0);
+            assert!((time.to_unix_timestamp(10.0, 60.0) - ts).abs() < 1.0);
+        }
+        
+        // Three days and a quarter in: fourth arm, quarter turn
+        let time = SpiralTime::from_unix_timestamp(3.25 * 86400.0, 0.0, 1.0);
+        assert_eq!(time.layer, 3);
+        assert!((time.angle - core::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    }
+    
+    #[test]
+    fn twelve_semitones_make_an_octave() {
+        assert!((Glyph::equal_temperament_frequency(432.0, 12) - 864.0).abs() < 1e-3);
+        assert!((Glyph::equal_temperament_frequency(432.0, -12) - 216.0).abs() < 1e-3);
+        
+        let a4 = Glyph::from_semitone(0, 0x1F300);
+        assert_eq!(a4.frequency, 432.0);
+        assert_eq!(a4.harmonics, [1.0, 1.0 / 2.0, 1.0 / 3.0, 0.25, 0.2, 1.0 / 6.0, 1.0 / 7.0]);
+    }
+    
+    #[test]
+    fn equal_temperament_quartet_spells_a_dominant_seventh() {
+        let score = SpiralScore::equal_temperament_quartet(440.0);
+        let ratios: Vec<f32> = score.musicians.iter().map(|m| m.frequency / 440.0).collect();
+        
+        for (ratio, semitones) in ratios.iter().zip([0, 7, 4, 10]) {
+            assert!((ratio - 2.0_f32.powf(semitones as f32 / 12.0)).abs() < 1e-5);
+        }
+        assert!(score.notes.is_empty());
+    }
+    
+    #[test]
+    fn a_lone_note_is_perfectly_concentrated() {
+        let mut score = SpiralScore::quartet();
+        score.add_note(0, SpiralTime { radius: 1.0, angle: 2.0, layer: 0 }, 0.5);
+        
+        assert_eq!(score.density_peak(4), (0, 1, 1));
+        assert_eq!(score.uniformity_score(4), 0.0);
+    }
+    
+    #[test]
+    fn one_note_per_cell_is_perfectly_uniform() {
+        let mut score = SpiralScore::quartet();
+        for ring in 0..4 {
+            for sector in 0..4 {
+                let angle = (sector as f32 + 0.5) * TAU / 4.0;
+                score.add_note(ring, SpiralTime { radius: ring as f32, angle, layer: ring as u8 }, 0.5);
+            }
+        }
+        
+        assert_eq!(score.density_map(4), vec![vec![1; 4]; 4]);
+        assert!((score.uniformity_score(4) - 1.0).abs() < 1e-6);
+    }
+    
+    #[test]
+    fn overtones_of_432_are_recognized() {
+        let mut score = SpiralScore::quartet();
+        for (i, frequency) in [432.0, 864.0, 1296.0, 500.0].into_iter().enumerate() {
+            score.musicians[i].frequency = frequency;
+            score.add_note(i, SpiralTime { radius: i as f32, angle: 0.0, layer: i as u8 }, 0.5);
+        }
+        
+        assert_eq!(score.detect_harmonics(432.0, 1.0), vec![(0, 1), (1, 2), (2, 3)]);
+        assert_eq!(
+            score.detect_partials_series(432.0, 4, 1.0),
+            vec![Some(0), Some(1), Some(2), None]
+        );
+    }
+    
+    #[test]
+    fn three_against_two_is_detected() {
+        // Musician 0 plays every 2 units, musician 1 every 3: three beats per two
+        let mut score = SpiralScore::quartet();
+        for beat in 0..7 {
+            score.add_note(0, SpiralTime { radius: beat as f32 * 2.0, angle: 0.0, layer: 0 }, 0.5);
+        }
+        for beat in 0..5 {
+            score.add_note(1, SpiralTime { radius: beat as f32 * 3.0, angle: 0.0, layer: 1 }, 0.5);
+        }
+        
+        assert!(score.polyrhythm_check(0, 1, (3, 2), 0.01));
+        assert!(!score.polyrhythm_check(0, 1, (2, 1), 0.01));
+        assert_eq!(score.detect_polyrhythm(0, 1), Some((3, 2)));
+        assert_eq!(score.detect_polyrhythm(0, 2), None);
+    }
+    
+    #[test]
+    fn lilypond_document_has_a_staff_per_musician() {
+        let ly = known_score().export_as_lilypond();
+        
+        assert!(ly.starts_with("\\version"));
+        assert!(ly.contains("\\new GrandStaff"));
+        assert_eq!(ly.matches("\\new Staff").count(), 4);
+        
+        assert_eq!(lilypond_pitch(432.0), "a'");
+        assert_eq!(lilypond_pitch(216.0), "a");
+        assert_eq!(lilypond_dynamic(0.5), "mp");
+    }
+    
+    #[test]
+    fn subdivision_doubles_only_the_loud_notes() {
+        let mut score = known_score();
+        score.subdivide_all_notes(0.45);
+        
+        // Four of the eight notes (0.5 to 0.8) split in two
+        assert_eq!(score.notes.len(), 12);
+        assert_eq!(score.notes.iter().filter(|note| note.amplitude > 0.45).count(), 0);
+        
+        let (first, second) = known_score().notes[7].split_at_midpoint(7.0, 8.0);
+        assert_eq!((first.time.radius, second.time.radius), (7.0, 7.5));
+        assert_eq!((first.amplitude, second.amplitude), (0.4, 0.4));
+    }
+    
+    #[test]
+    fn pseudo_cid_keeps_the_first_28_bytes() {
+        // Every byte group uses at most 24 significant bits, as an f32 holds
+        let mut cid = [0u8; 32];
+        for (i, byte) in cid.iter_mut().enumerate() {
+            *byte = if i % 4 == 0 { 0 } else { (i as u8).wrapping_mul(37) | 1 };
+        }
+        
+        let back = trajectory_to_pseudo_cid(&cid_to_trajectory(&cid));
+        assert_eq!(back[..28], cid[..28]);
+        assert_eq!(back[28..], [0; 4]);
+        
+        let full = trajectory_to_pseudo_cid(&TrajectoryPoint::from_array(&[1.0; 7]));
+        assert_eq!(full[..28], [0xFF; 28]);
+    }
+    
+    /// The known score transposed by `pitch`, stretched by `stretch` and played at `volume`
+    fn transposed_score(pitch: f32, stretch: f32, volume: f32) -> SpiralScore {
+        let mut score = SpiralScore::quartet();
+        for musician in score.musicians.iter_mut() {
+            musician.frequency *= pitch;
+        }
+        for note in known_score().notes {
+            let time = SpiralTime { radius: note.time.radius * stretch, ..note.time };
+            let musician = score.musicians.iter().position(|m| m.symbol == note.glyph.symbol).unwrap();
+            score.add_note(musician, time, note.amplitude * volume);
+        }
+        score
+    }
+    
+    #[test]
+    fn transposed_scores_share_a_canonical_form() {
+        let original = transposed_score(1.0, 1.0, 1.0);
+        let transposed = transposed_score(1.5, 3.0, 0.5);
+        
+        assert!(original.is_canonical_equivalent(&transposed, 1e-5));
+        assert!(transposed.is_canonical_equivalent(&original, 1e-5));
+        assert!(!original.is_canonical_equivalent(&SpiralScore::quartet(), 1e-5));
+        
+        let canonical = transposed.canonical_form();
+        assert_eq!(canonical.musicians[0].frequency, 1.0);
+        assert!(canonical.notes.iter().all(|note| note.time.radius <= 1.0 && note.amplitude <= 1.0));
+    }
+    
+    /// One note at each radius, cycling through the quartet
+    fn score_at_radii(radii: &[f32]) -> SpiralScore {
+        let mut score = SpiralScore::quartet();
+        for (i, &radius) in radii.iter().enumerate() {
+            score.add_note(i % 4, SpiralTime { radius, angle: 0.0, layer: (i % 4) as u8 }, 0.5);
+        }
+        score
+    }
+    
+    #[test]
+    fn fibonacci_radii_have_a_fibonacci_rhythm() {
+        let score = score_at_radii(&[1.0, 2.0, 3.0, 5.0, 8.0, 13.0]);
+        assert!(score.detect_fibonacci_rhythm(0.01));
+        assert!((score.fibonacci_rhythmic_score() - 1.0).abs() < 1e-6);
+        
+        let steady = score_at_radii(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        assert!(!steady.detect_fibonacci_rhythm(0.01));
+        assert!(steady.fibonacci_rhythmic_score() < 0.5);
+    }
+    
+    #[test]
+    fn quantizing_moves_notes_at_most_half_a_beat() {
+        let spiral = TimeSpiral::golden();
+        let times: Vec<f32> = (0..40).map(|k| k as f32 * 0.137).collect();
+        let radii: Vec<f32> = times.iter().map(|&t| spiral.linearize(t).radius).collect();
+        let mut score = score_at_radii(&radii);
+        score.quantize_to_grid(120.0);
+        
+        for (note, original) in score.notes.iter().zip(times.iter()) {
+            let t = spiral.delinearize(&note.time).unwrap();
+            assert!((t - original).abs() <= 0.25 + 1e-3, "{original} moved to {t}");
+            assert!((t / 0.5 - (t / 0.5).round()).abs() < 1e-3);
+        }
+    }
+    
+    #[test]
+    fn a_pure_432_score_has_no_spread() {
+        let mut score = SpiralScore::quartet();
+        for i in 0..6 {
+            let time = SpiralTime { radius: i as f32, angle: 0.0, layer: 0 };
+            score.add_note(if i % 2 == 0 { 0 } else { 3 }, time, 0.2 + i as f32 / 10.0);
+        }
+        
+        assert_eq!(score.spectral_centroid(), 432.0);
+        assert_eq!(score.spectral_bandwidth(), 0.0);
+        assert_eq!(score.spectral_rolloff(0.85), 432.0);
+    }
+    
+    #[test]
+    fn rolloff_climbs_with_the_threshold() {
+        // Energy per frequency: 432 Hz 1.06, 528 Hz 0.40, 639 Hz 0.58
+        let score = known_score();
+        
+        assert_eq!(score.spectral_rolloff(0.5), 432.0);
+        assert_eq!(score.spectral_rolloff(0.6), 528.0);
+        assert_eq!(score.spectral_rolloff(1.0), 639.0);
+        assert!(score.spectral_bandwidth() > 0.0);
+        
+        let expected = (432.0 * 1.8 + 528.0 * 0.8 + 639.0 * 1.0) / 3.6;
+        assert!((score.spectral_centroid() - expected).abs() < 1e-3);
+    }
 }
\ No newline at end of file
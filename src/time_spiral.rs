@@ -9,6 +9,7 @@
 
 use crate::spiral_score::{SpiralTime, SpiralScore, Glyph};
 use crate::glyph_hash::GlyphHash;
+use core::f32::consts::TAU;
 
 /// The Time Spiral - where all moments coexist
 #[repr(C)]
@@ -44,6 +45,16 @@ impl TimeSpiral {
         }
     }
     
+    /// The linear time a spiral moment stands for - `linearize` read back
+    /// from the radius (r = a · e^(ωt/2π)); None for a radius off the spiral
+    pub fn delinearize(&self, time: &SpiralTime) -> Option<f32> {
+        if time.radius <= 0.0 || self.radius_growth <= 0.0 || self.angular_velocity == 0.0 {
+            return None;
+        }
+        
+        Some((time.radius / self.radius_growth).ln() * TAU / self.angular_velocity)
+    }
+    
     /// See into the future (approximate partiture)
     pub fn future_vision(&self, current: &SpiralTime, distance: f32) -> SpiralTime {
         let future_angle = current.angle + (distance * self.angular_velocity);
@@ -71,6 +82,148 @@ impl TimeSpiral {
         
         layer_resonance * angular_harmony * radius_ratio
     }
+    
+    /// `steps` moments evenly spaced from t_start to t_end (both included)
+    pub fn segment_between(&self, t_start: f32, t_end: f32, steps: usize) -> Vec<SpiralTime> {
+        (0..steps)
+            .map(|k| {
+                let t = if k + 1 == steps {
+                    t_end  // Land exactly on the end
+                } else if steps > 1 {
+                    t_start + (t_end - t_start) * k as f32 / (steps - 1) as f32
+                } else {
+                    t_start
+                };
+                self.linearize(t)
+            })
+            .collect()
+    }
+    
+    /// Every beat of `bars` bars of 4/4 at `bpm`, placed on the spiral
+    /// (beat k sounds at k · 60/bpm seconds)
+    pub fn synchronize_with_beats(&self, bpm: f32, bars: u32) -> Vec<SpiralTime> {
+        if bpm <= 0.0 {
+            return Vec::new();
+        }
+        
+        let beat = 60.0 / bpm;
+        (0..bars * 4)
+            .map(|k| self.linearize(k as f32 * beat))
+            .collect()
+    }
+    
+    /// Snap a moment to the nearest beat at `bpm` in linear time, then put
+    /// it back on the spiral - the beats of `synchronize_with_beats` stay put
+    /// Moments off the spiral (or a non-positive bpm) are left unchanged
+    pub fn quantize_to_beat(&self, time: &SpiralTime, bpm: f32) -> SpiralTime {
+        match self.delinearize(time) {
+            Some(t) if bpm > 0.0 => {
+                let beat = 60.0 / bpm;
+                self.linearize((t / beat).round() * beat)
+            }
+            _ => SpiralTime { radius: time.radius, angle: time.angle, layer: time.layer },
+        }
+    }
+    
+    /// Length of the spiral arc, as a polyline through `steps` samples
+    pub fn arc_length(&self, t_start: f32, t_end: f32, steps: usize) -> f32 {
+        self.segment_between(t_start, t_end, steps)
+            .windows(2)
+            .map(|pair| distance(&pair[0], &pair[1]))
+            .sum()
+    }
+    
+    /// Arc length from t = 0 to each of `resolution` evenly spaced times up to
+    /// `max_t` - entry i answers for t = max_t · i / (resolution - 1)
+    pub fn time_to_arc_length_lut(&self, max_t: f32, resolution: usize) -> Vec<f32> {
+        let mut total = 0.0;
+        let mut lut = Vec::with_capacity(resolution);
+        let segment = self.segment_between(0.0, max_t, resolution);
+        
+        for (i, moment) in segment.iter().enumerate() {
+            if i > 0 {
+                total += distance(&segment[i - 1], moment);
+            }
+            lut.push(total);
+        }
+        lut
+    }
+    
+    /// Resonance between all seven layers, each sampled once at `radius`
+    /// with its arm spread evenly around the spiral (angle = layer · 2π/7)
+    pub fn layer_resonances(&self, radius: f32) -> [[f32; 7]; 7] {
+        let moments: [SpiralTime; 7] = core::array::from_fn(|layer| SpiralTime {
+            radius,
+            angle: layer as f32 * TAU / 7.0,
+            layer: layer as u8,
+        });
+        
+        let mut resonances = [[0.0f32; 7]; 7];
+        for (row, a) in resonances.iter_mut().zip(moments.iter()) {
+            for (cell, b) in row.iter_mut().zip(moments.iter()) {
+                *cell = self.temporal_resonance(a, b);
+            }
+        }
+        resonances
+    }
+    
+    /// The two distinct layers that resonate most strongly at `radius`
+    pub fn dominant_layer_pair(&self, radius: f32) -> (u8, u8) {
+        let resonances = self.layer_resonances(radius);
+        let mut best = (0u8, 1u8);
+        
+        for i in 0..7 {
+            for j in (i + 1)..7 {
+                if resonances[i][j] > resonances[best.0 as usize][best.1 as usize] {
+                    best = (i as u8, j as u8);
+                }
+            }
+        }
+        best
+    }
+    
+    /// How many times a closed time loop wraps around the spiral center
+    pub fn winding_number(closed_path: &[SpiralTime]) -> i32 {
+        if closed_path.len() < 2 {
+            return 0;
+        }
+        
+        let mut total_angle = 0.0f32;
+        
+        // Walk the loop, returning from the last point to the first
+        for (i, from) in closed_path.iter().enumerate() {
+            let to = &closed_path[(i + 1) % closed_path.len()];
+            let (x1, y1) = to_cartesian(from);
+            let (x2, y2) = to_cartesian(to);
+            
+            // Signed angle swept around the origin
+            let cross = x1 * y2 - y1 * x2;
+            let dot = x1 * x2 + y1 * y2;
+            total_angle += cross.atan2(dot);
+        }
+        
+        (total_angle / TAU).round() as i32
+    }
+    
+    /// Does the path end where it began?
+    pub fn is_closed(path: &[SpiralTime], epsilon: f32) -> bool {
+        match (path.first(), path.last()) {
+            (Some(first), Some(last)) => distance(first, last) <= epsilon,
+            _ => false,
+        }
+    }
+}
+
+/// Spiral polar coordinates to the flat plane
+fn to_cartesian(time: &SpiralTime) -> (f32, f32) {
+    (time.radius * time.angle.cos(), time.radius * time.angle.sin())
+}
+
+/// Straight-line distance between two moments on the flat plane
+fn distance(a: &SpiralTime, b: &SpiralTime) -> f32 {
+    let (x1, y1) = to_cartesian(a);
+    let (x2, y2) = to_cartesian(b);
+    ((x2 - x1) * (x2 - x1) + (y2 - y1) * (y2 - y1)).sqrt()
 }
 
 /// Pattern that plays patterns - recursive conductor
@@ -126,6 +279,105 @@ impl MetaConductor {
         
         1.0 / (1.0 + identity_distance)
     }
+    
+    /// Follow the pattern for `steps` iterations, recording its orbit
+    pub fn visualize_attractor(&mut self, seed: &[f32; 7], steps: usize) -> Vec<[f32; 7]> {
+        let mut orbit = Vec::with_capacity(steps);
+        let mut state = *seed;
+        
+        for _ in 0..steps {
+            state = self.recursive_conduct(&state, self.depth.max(1));
+            orbit.push(self.pattern_cache);
+        }
+        
+        orbit
+    }
+    
+    /// Structural similarity of two patterns (1D SSIM over the seven layers)
+    /// 1.0 = same structure, near 0 = fundamentally different
+    pub fn score_similarity(a: &[f32; 7], b: &[f32; 7]) -> f32 {
+        const C1: f32 = 0.01 * 0.01;
+        const C2: f32 = 0.03 * 0.03;
+        
+        let mean_a = a.iter().sum::<f32>() / 7.0;
+        let mean_b = b.iter().sum::<f32>() / 7.0;
+        
+        let (mut var_a, mut var_b, mut cov_ab) = (0.0, 0.0, 0.0);
+        for (&x, &y) in a.iter().zip(b.iter()) {
+            var_a += (x - mean_a) * (x - mean_a);
+            var_b += (y - mean_b) * (y - mean_b);
+            cov_ab += (x - mean_a) * (y - mean_b);
+        }
+        var_a /= 7.0;
+        var_b /= 7.0;
+        cov_ab /= 7.0;
+        
+        ((2.0 * mean_a * mean_b + C1) * (2.0 * cov_ab + C2))
+            / ((mean_a * mean_a + mean_b * mean_b + C1) * (var_a + var_b + C2))
+    }
+    
+    /// How far the cached pattern has wandered from `reference`
+    pub fn pattern_drift(&self, reference: &[f32; 7]) -> f32 {
+        1.0 - Self::score_similarity(&self.pattern_cache, reference)
+    }
+}
+
+/// Box-counting dimension of an orbit over a 10×10 grid (first two layers)
+pub fn attractor_dimension(orbit: &[[f32; 7]]) -> f32 {
+    const GRID: usize = 10;
+    
+    if orbit.is_empty() {
+        return 0.0;
+    }
+    
+    // Bounding box of the projection
+    let (mut min_x, mut max_x) = (f32::MAX, f32::MIN);
+    let (mut min_y, mut max_y) = (f32::MAX, f32::MIN);
+    for point in orbit {
+        min_x = min_x.min(point[0]);
+        max_x = max_x.max(point[0]);
+        min_y = min_y.min(point[1]);
+        max_y = max_y.max(point[1]);
+    }
+    
+    let width = (max_x - min_x).max(f32::EPSILON);
+    let height = (max_y - min_y).max(f32::EPSILON);
+    
+    let mut occupied = [[false; GRID]; GRID];
+    for point in orbit {
+        let col = (((point[0] - min_x) / width) * GRID as f32) as usize;
+        let row = (((point[1] - min_y) / height) * GRID as f32) as usize;
+        occupied[row.min(GRID - 1)][col.min(GRID - 1)] = true;
+    }
+    
+    let boxes = occupied.iter().flatten().filter(|&&cell| cell).count();
+    
+    // N(ε) = ε^-D with ε = 1/10
+    (boxes as f32).ln() / (GRID as f32).ln()
+}
+
+/// Largest Lyapunov exponent from successive trajectory divergences
+/// Positive = chaos, negative = the pattern settles
+pub fn attractor_lyapunov(orbit: &[[f32; 7]]) -> f32 {
+    let steps: Vec<f32> = orbit.windows(2)
+        .map(|pair| {
+            pair[0].iter().zip(pair[1].iter())
+                .map(|(a, b)| (b - a) * (b - a))
+                .sum::<f32>()
+                .sqrt()
+        })
+        .collect();
+    
+    let mut sum = 0.0f32;
+    let mut count = 0;
+    for pair in steps.windows(2) {
+        if pair[0] > 0.0 && pair[1] > 0.0 {
+            sum += (pair[1] / pair[0]).ln();
+            count += 1;
+        }
+    }
+    
+    if count > 0 { sum / count as f32 } else { 0.0 }
 }
 
 /// Musicians adjusting to past and future
@@ -256,4 +508,162 @@ fn ln(x: f32) -> f32 {
     }
     
     2.0 * result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f32::consts::PI;
+    
+    #[test]
+    fn orbit_has_one_point_per_step() {
+        let mut conductor = MetaConductor::new(3);
+        let orbit = conductor.visualize_attractor(&[0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7], 25);
+        
+        assert_eq!(orbit.len(), 25);
+        assert_eq!(orbit[24], conductor.pattern_cache);
+        assert!(conductor.visualize_attractor(&[0.5; 7], 0).is_empty());
+    }
+    
+    #[test]
+    fn golden_stretching_is_chaotic() {
+        // Leaving the repelling fixed point at 0, each step stretches by φ
+        let mut conductor = MetaConductor::new(1);
+        conductor.self_reference = 0.0;
+        let orbit = conductor.visualize_attractor(&[1e-4; 7], 12);
+        
+        assert!(attractor_lyapunov(&orbit) > 0.0);
+        assert!((attractor_lyapunov(&orbit) - 1.618034f32.ln()).abs() < 1e-2);
+    }
+    
+    #[test]
+    fn single_point_has_dimension_zero() {
+        assert_eq!(attractor_dimension(&[[0.5; 7]; 10]), 0.0);
+        assert_eq!(attractor_dimension(&[]), 0.0);
+    }
+    
+    /// `steps` moments at radius 1 from `start` to `end` radians
+    fn arc(start: f32, end: f32, steps: usize) -> Vec<SpiralTime> {
+        (0..steps)
+            .map(|k| {
+                let angle = start + (end - start) * k as f32 / (steps - 1) as f32;
+                SpiralTime { radius: 1.0, angle, layer: 0 }
+            })
+            .collect()
+    }
+    
+    #[test]
+    fn circle_winds_once_and_arc_not_at_all() {
+        let circle = arc(0.0, TAU, 37);
+        assert!(TimeSpiral::is_closed(&circle, 1e-4));
+        assert_eq!(TimeSpiral::winding_number(&circle), 1);
+        
+        let backwards = arc(TAU, 0.0, 37);
+        assert_eq!(TimeSpiral::winding_number(&backwards), -1);
+        
+        let half = arc(0.0, PI / 2.0, 10);
+        assert!(!TimeSpiral::is_closed(&half, 1e-4));
+        assert_eq!(TimeSpiral::winding_number(&half), 0);
+    }
+    
+    #[test]
+    fn layer_resonances_are_symmetric_with_a_perfect_diagonal() {
+        let spiral = TimeSpiral::golden();
+        let resonances = spiral.layer_resonances(2.0);
+        
+        for i in 0..7 {
+            assert!((resonances[i][i] - 1.0).abs() < 1e-6);
+            for j in 0..7 {
+                assert!((resonances[i][j] - resonances[j][i]).abs() < 1e-6);
+                if i != j {
+                    // Different arms never get past the cross-arm penalty
+                    assert!(resonances[i][j] <= 0.5);
+                }
+            }
+        }
+        
+        let (a, b) = spiral.dominant_layer_pair(2.0);
+        let strongest = (0..7)
+            .flat_map(|i| (0..7).filter(move |&j| j != i).map(move |j| (i, j)))
+            .map(|(i, j)| resonances[i][j])
+            .fold(0.0f32, f32::max);
+        assert!(a < b);
+        assert_eq!(resonances[a as usize][b as usize], strongest);
+    }
+    
+    #[test]
+    fn sharing_an_arm_strengthens_resonance() {
+        let spiral = TimeSpiral::golden();
+        let here = SpiralTime { radius: 2.0, angle: 0.3, layer: 2 };
+        let same_arm = SpiralTime { radius: 2.5, angle: 1.1, layer: 2 };
+        let other_arm = SpiralTime { layer: 5, ..same_arm };
+        
+        let together = spiral.temporal_resonance(&here, &same_arm);
+        let apart = spiral.temporal_resonance(&here, &other_arm);
+        assert!(together > apart);
+    }
+    
+    #[test]
+    fn two_step_segment_is_just_its_endpoints() {
+        let spiral = TimeSpiral::golden();
+        let segment = spiral.segment_between(0.0, 3.5, 2);
+        
+        assert_eq!(segment.len(), 2);
+        for (moment, t) in segment.iter().zip([0.0, 3.5]) {
+            let expected = spiral.linearize(t);
+            assert_eq!(moment.radius, expected.radius);
+            assert_eq!(moment.angle, expected.angle);
+            assert_eq!(moment.layer, expected.layer);
+        }
+    }
+    
+    #[test]
+    fn arc_length_table_accumulates_to_the_full_arc() {
+        let spiral = TimeSpiral::golden();
+        let lut = spiral.time_to_arc_length_lut(2.0, 50);
+        
+        assert_eq!(lut.len(), 50);
+        assert_eq!(lut[0], 0.0);
+        assert!(lut.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert!((lut[49] - spiral.arc_length(0.0, 2.0, 50)).abs() < 1e-4);
+    }
+    
+    #[test]
+    fn a_pattern_is_structurally_identical_to_itself() {
+        let pattern = [0.1, 0.7, 0.3, 0.9, 0.2, 0.6, 0.4];
+        assert!((MetaConductor::score_similarity(&pattern, &pattern) - 1.0).abs() < 1e-6);
+        assert!(MetaConductor::score_similarity(&[0.0; 7], &[1.0; 7]) < 0.01);
+        
+        let conductor = MetaConductor::new(3);
+        assert!(conductor.pattern_drift(&conductor.pattern_cache).abs() < 1e-6);
+    }
+    
+    #[test]
+    fn one_bar_at_120_bpm_has_four_beats() {
+        let spiral = TimeSpiral::golden();
+        let beats = spiral.synchronize_with_beats(120.0, 1);
+        
+        assert_eq!(beats.len(), 4);
+        for (k, beat) in beats.iter().enumerate() {
+            assert_eq!(beat.radius, spiral.linearize(k as f32 * 0.5).radius);
+        }
+        assert!(spiral.synchronize_with_beats(0.0, 4).is_empty());
+    }
+    
+    #[test]
+    fn synchronized_beats_are_fixed_points_of_quantizing() {
+        let spiral = TimeSpiral::golden();
+        
+        for beat in spiral.synchronize_with_beats(120.0, 3) {
+            for snapped in [spiral.quantize_to_beat(&beat, 120.0), beat.quantize_to_beat(120.0)] {
+                assert_eq!(snapped.radius, beat.radius);
+                assert_eq!(snapped.angle, beat.angle);
+                assert_eq!(snapped.layer, beat.layer);
+            }
+        }
+        
+        let off_spiral = SpiralTime { radius: 0.0, angle: 1.0, layer: 2 };
+        assert_eq!(spiral.delinearize(&off_spiral), None);
+        assert_eq!(spiral.quantize_to_beat(&off_spiral, 120.0).angle, 1.0);
+    }
 }
\ No newline at end of file
@@ -9,6 +9,7 @@
 
 use crate::time_spiral::TimeSpiral;
 use crate::spiral_score::SpiralTime;
+use core::f32::consts::{PI, TAU};
 
 /// Git - the light thread moving forward
 #[repr(C)]
@@ -34,6 +35,17 @@ pub struct TimeWeavingLoom {
     pub orbital_radius: f32,        // Current distance from present
     pub orbital_phase: f32,         // Position in orbital cycle
     pub weave_pattern: Vec<[f32; 7]>, // The mandala being woven
+    pub phase_history: Vec<f32>,    // Orbital phase each step was woven at
+    pub kalman: LoomKalmanState,    // Filtered view of the weave
+}
+
+/// Per-layer Kalman filter over the weave (layers filtered independently)
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct LoomKalmanState {
+    pub estimate: [f32; 7],    // Best guess of the true weave
+    pub covariance: [f32; 7],  // Uncertainty of that guess
+    pub filtered_steps: usize, // Weave steps already observed
 }
 
 impl TimeWeavingLoom {
@@ -54,36 +66,121 @@ impl TimeWeavingLoom {
             orbital_radius: 1.0,
             orbital_phase: 0.0,
             weave_pattern: Vec::new(),
+            phase_history: Vec::new(),
+            kalman: LoomKalmanState {
+                estimate: *present,  // Before any weave, trust the present
+                covariance: [1.0; 7],
+                filtered_steps: 0,
+            },
         }
     }
     
     /// Weave forward (Git) and backward (Mercurial) threads
     pub fn weave(&mut self, forward: &[f32; 7], backward: &[f32; 7]) -> [f32; 7] {
+        let woven = self.woven_at(forward, backward, self.orbital_phase, self.orbital_radius);
+        
+        // Add to mandala pattern
+        self.weave_pattern.push(woven);
+        self.phase_history.push(self.orbital_phase);
+        
+        // Update orbital position
+        self.orbital_phase = (self.orbital_phase + 0.1) % TAU;
+        
+        woven
+    }
+    
+    /// What `weave` would produce at a given orbital phase and radius
+    fn woven_at(&self, forward: &[f32; 7], backward: &[f32; 7], phase: f32, radius: f32) -> [f32; 7] {
         let mut woven = [0.0f32; 7];
         
         // Interleave the threads like warp and weft
         for i in 0..7 {
             // Git provides structure (warp)
-            let warp = forward[i] * (1.0 + self.orbital_phase.cos());
+            let warp = forward[i] * (1.0 + phase.cos());
             
             // Mercurial provides flexibility (weft)
-            let weft = backward[i] * (1.0 + self.orbital_phase.sin());
+            let weft = backward[i] * (1.0 + phase.sin());
             
             // Weave them together
             woven[i] = (warp + weft) / 2.0;
             
             // Apply present gravity
-            woven[i] = woven[i] * (1.0 - 1.0/self.orbital_radius) 
-                     + self.present_gravity[i] * (1.0/self.orbital_radius);
+            woven[i] = woven[i] * (1.0 - 1.0/radius) 
+                     + self.present_gravity[i] * (1.0/radius);
         }
         
-        // Add to mandala pattern
-        self.weave_pattern.push(woven);
+        woven
+    }
+    
+    /// Search the orbit for the phase and radius whose weave lands closest
+    /// to `target` (hill climbing with 4 restarts, steps shrinking as 1/k),
+    /// then weave there. Returns the L2 distance of that weave to the target
+    pub fn optimize_weave(
+        &mut self,
+        forward: &[f32; 7],
+        backward: &[f32; 7],
+        target: &[f32; 7],
+        iterations: usize
+    ) -> f32 {
+        const RESTARTS: usize = 4;
+        const PHI: f32 = 1.618034;
         
-        // Update orbital position
-        self.orbital_phase = (self.orbital_phase + 0.1) % (2.0 * 3.14159);
+        let miss = |phase: f32, radius: f32| -> f32 {
+            self.woven_at(forward, backward, phase, radius)
+                .iter()
+                .zip(target.iter())
+                .map(|(a, b)| (a - b) * (a - b))
+                .sum::<f32>()
+                .sqrt()
+        };
         
-        woven
+        // Deterministic pseudo-random walk (xorshift), no entropy source needed
+        let mut seed = 0x9E37_79B9u32;
+        let mut random = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            seed as f32 / u32::MAX as f32
+        };
+        
+        let mut best = (self.orbital_phase, self.orbital_radius);
+        let mut best_miss = miss(best.0, best.1);
+        let per_restart = iterations.div_ceil(RESTARTS).max(1);
+        
+        for restart in 0..RESTARTS.min(iterations) {
+            // Restarts spread over the orbit by golden-ratio steps; the first keeps the best so far
+            let mut current = if restart == 0 {
+                best
+            } else {
+                ((restart as f32 * PHI).fract() * TAU, 0.1 + (restart as f32 * PHI * PHI).fract() * 9.9)
+            };
+            let mut current_miss = miss(current.0, current.1);
+            
+            for k in 1..=per_restart {
+                let scale = 1.0 / k as f32;
+                let candidate = (
+                    (current.0 + (random() - 0.5) * PI * scale).rem_euclid(TAU),
+                    (current.1 + (random() - 0.5) * 5.0 * scale).clamp(0.1, 10.0),
+                );
+                let candidate_miss = miss(candidate.0, candidate.1);
+                
+                if candidate_miss < current_miss {
+                    current = candidate;
+                    current_miss = candidate_miss;
+                }
+            }
+            
+            if current_miss < best_miss {
+                best = current;
+                best_miss = current_miss;
+            }
+        }
+        
+        self.orbital_phase = best.0;
+        self.orbital_radius = best.1;
+        self.weave(forward, backward);
+        
+        best_miss
     }
     
     /// Calculate elliptical orbit around present
@@ -140,6 +237,230 @@ impl TimeWeavingLoom {
         
         mandala
     }
+    
+    /// Replay every recorded weave step in order
+    pub fn replay_history(&self) -> impl Iterator<Item = &[f32; 7]> {
+        self.weave_pattern.iter()
+    }
+    
+    /// Replay the weave starting from a given step index
+    pub fn replay_from(&self, step: usize) -> impl Iterator<Item = &[f32; 7]> {
+        self.weave_pattern.iter().skip(step)
+    }
+    
+    /// Forget all but the last `keep_last` weave steps (long-running syntheses)
+    pub fn trim_history(&mut self, keep_last: usize) {
+        let len = self.weave_pattern.len();
+        if len > keep_last {
+            self.weave_pattern.drain(..len - keep_last);
+            
+            // The filter has still seen what it saw, minus the forgotten steps
+            let forgotten = len - keep_last;
+            self.kalman.filtered_steps = self.kalman.filtered_steps.saturating_sub(forgotten);
+        }
+        
+        let len = self.phase_history.len();
+        if len > keep_last {
+            self.phase_history.drain(..len - keep_last);
+        }
+    }
+    
+    /// Drift toward a target phase by at most `speed` radians
+    pub fn synchronize_phase(&mut self, target_phase: f32, speed: f32) {
+        // Shortest way around the orbit, in (-π, π]
+        let mut diff = (target_phase - self.orbital_phase).rem_euclid(TAU);
+        if diff > PI {
+            diff -= TAU;
+        }
+        
+        let step = diff.clamp(-speed, speed);
+        self.orbital_phase = (self.orbital_phase + step).rem_euclid(TAU);
+    }
+    
+    /// Higuchi fractal dimension of the weave (each step projected to its norm)
+    /// ~1.0 = smooth curve, ~1.5 = random walk, ~2.0 = space-filling noise
+    pub fn fractal_dimension(&self) -> f32 {
+        let series: Vec<f32> = self.weave_pattern.iter()
+            .map(|p| p.iter().map(|x| x * x).sum::<f32>().sqrt())
+            .collect();
+        let n = series.len();
+        let k_max = (n / 4).min(8);
+        
+        if k_max < 2 {
+            return 1.0;  // Too short to show any structure
+        }
+        
+        // Curve length at each time scale k
+        let mut points: Vec<(f32, f32)> = Vec::with_capacity(k_max);
+        for k in 1..=k_max {
+            let mut total = 0.0;
+            for m in 0..k {
+                let steps = (n - 1 - m) / k;
+                let length: f32 = (1..=steps)
+                    .map(|i| (series[m + i * k] - series[m + (i - 1) * k]).abs())
+                    .sum();
+                total += length * (n - 1) as f32 / (steps * k * k) as f32;
+            }
+            
+            let mean_length = total / k as f32;
+            if mean_length <= 0.0 {
+                return 1.0;  // A perfectly still weave is smooth
+            }
+            points.push(((1.0 / k as f32).ln(), mean_length.ln()));
+        }
+        
+        // Least-squares slope of ln L(k) against ln(1/k)
+        let count = points.len() as f32;
+        let mean_x = points.iter().map(|p| p.0).sum::<f32>() / count;
+        let mean_y = points.iter().map(|p| p.1).sum::<f32>() / count;
+        let covariance: f32 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+        let variance: f32 = points.iter().map(|p| (p.0 - mean_x) * (p.0 - mean_x)).sum();
+        
+        covariance / variance
+    }
+    
+    /// Whether the weave is rougher than `threshold`
+    pub fn is_fractal(&self, threshold: f32) -> bool {
+        self.fractal_dimension() > threshold
+    }
+    
+    /// Pearson correlation of the step norms of two weaves (the shorter one
+    /// padded with silence): 1 = entangled, 0 = independent, -1 = anti-entangled
+    pub fn entanglement_measure(&self, other: &TimeWeavingLoom) -> f32 {
+        let n = self.weave_pattern.len().max(other.weave_pattern.len());
+        if n < 2 {
+            return 0.0;
+        }
+        
+        let norms = |loom: &TimeWeavingLoom| -> Vec<f32> {
+            (0..n)
+                .map(|i| loom.weave_pattern.get(i).map_or(0.0, |p| p.iter().map(|x| x * x).sum::<f32>().sqrt()))
+                .collect()
+        };
+        let (a, b) = (norms(self), norms(other));
+        
+        let mean_a = a.iter().sum::<f32>() / n as f32;
+        let mean_b = b.iter().sum::<f32>() / n as f32;
+        let mut covariance = 0.0;
+        let mut variance_a = 0.0;
+        let mut variance_b = 0.0;
+        for (x, y) in a.iter().zip(b.iter()) {
+            covariance += (x - mean_a) * (y - mean_b);
+            variance_a += (x - mean_a) * (x - mean_a);
+            variance_b += (y - mean_b) * (y - mean_b);
+        }
+        
+        if variance_a == 0.0 || variance_b == 0.0 {
+            return 0.0;  // A steady weave carries no correlation
+        }
+        (covariance / (variance_a * variance_b).sqrt()).clamp(-1.0, 1.0)
+    }
+    
+    /// Turn each weave step away from the matching step of `other`,
+    /// shedding `strength` (0-1) of the component the two share
+    pub fn disentangle(&mut self, other: &TimeWeavingLoom, strength: f32) {
+        for (step, theirs) in self.weave_pattern.iter_mut().zip(other.weave_pattern.iter()) {
+            let energy: f32 = theirs.iter().map(|x| x * x).sum();
+            if energy == 0.0 {
+                continue;
+            }
+            
+            let overlap = step.iter().zip(theirs.iter()).map(|(a, b)| a * b).sum::<f32>() / energy;
+            for (x, y) in step.iter_mut().zip(theirs.iter()) {
+                *x -= strength * overlap * y;
+            }
+        }
+    }
+    
+    /// Feed the weave steps the Kalman filter has not seen yet through it,
+    /// in order, and return the filtered weave (unchanged when nothing new
+    /// was woven). The state is assumed constant between steps (identity
+    /// transition) and each step observes it directly
+    pub fn kalman_filter_weave(&mut self, measurement_noise: f32, process_noise: f32) -> [f32; 7] {
+        let state = &mut self.kalman;
+        let unseen = self.weave_pattern.get(state.filtered_steps..).unwrap_or(&[]);
+        
+        for measurement in unseen {
+            let layers = state.estimate.iter_mut().zip(state.covariance.iter_mut());
+            for ((estimate, covariance), observed) in layers.zip(measurement) {
+                // Predict: the state holds, uncertainty grows
+                let predicted = *covariance + process_noise;
+                
+                // Update: trust the observation in proportion to our uncertainty
+                let gain = predicted / (predicted + measurement_noise);
+                *estimate += gain * (observed - *estimate);
+                *covariance = (1.0 - gain) * predicted;
+            }
+        }
+        state.filtered_steps = self.weave_pattern.len();
+        
+        state.estimate
+    }
+    
+    /// Orbital resonance between git commits and mercurial revisions
+    /// Returns the reduced ratio if both sides are small (≤ 10)
+    pub fn orbital_resonance_ratio(&self) -> Option<(u32, u32)> {
+        let (commits, revisions) = (self.git.commits, self.mercurial.revisions);
+        if commits == 0 || revisions == 0 {
+            return None;  // No orbit, no resonance
+        }
+        
+        let divisor = gcd(commits, revisions);
+        let (p, q) = (commits / divisor, revisions / divisor);
+        
+        if p <= 10 && q <= 10 { Some((p, q)) } else { None }
+    }
+    
+    /// How long the resonance survives perturbation
+    /// 1:1 → 1.0, 2:1 → 0.5, 3:1 → 0.33, ... (0 without resonance)
+    pub fn resonance_stability(&self) -> f32 {
+        match self.orbital_resonance_ratio() {
+            Some((p, q)) => 1.0 / (p + q - 1) as f32,
+            None => 0.0,
+        }
+    }
+}
+
+/// Greatest common divisor (Euclid)
+fn gcd(mut a: u32, mut b: u32) -> u32 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Mean phase vector of a group of looms: (cos, sin)
+fn mean_phase_vector(looms: &[TimeWeavingLoom]) -> (f32, f32) {
+    let n = looms.len() as f32;
+    let cos = looms.iter().map(|l| l.orbital_phase.cos()).sum::<f32>() / n;
+    let sin = looms.iter().map(|l| l.orbital_phase.sin()).sum::<f32>() / n;
+    (cos, sin)
+}
+
+/// Phase-lock several consciousness streams around their mean phase
+pub fn phase_lock(looms: &mut [TimeWeavingLoom], speed: f32) {
+    if looms.is_empty() {
+        return;
+    }
+    
+    let (cos, sin) = mean_phase_vector(looms);
+    let mean_phase = sin.atan2(cos);
+    
+    for loom in looms.iter_mut() {
+        loom.synchronize_phase(mean_phase, speed);
+    }
+}
+
+/// Circular variance of loom phases (0 = perfectly locked, 1 = scattered)
+pub fn phase_coherence(looms: &[TimeWeavingLoom]) -> f32 {
+    if looms.is_empty() {
+        return 0.0;
+    }
+    
+    let (cos, sin) = mean_phase_vector(looms);
+    1.0 - (cos * cos + sin * sin).sqrt()
 }
 
 /// Harmonize two civilizations through orbital dance
@@ -277,4 +598,202 @@ impl TimeWeavingLoom {
     fn sqrt(&self, x: f32) -> f32 {
         sqrt(x)
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const FORWARD: [f32; 7] = [0.8, 0.7, 0.6, 0.5, 0.4, 0.3, 0.2];
+    const BACKWARD: [f32; 7] = [0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8];
+    
+    /// A loom that has woven `steps` times
+    fn woven_loom(steps: usize) -> TimeWeavingLoom {
+        let mut loom = TimeWeavingLoom::new(&[0.5; 7]);
+        for _ in 0..steps {
+            loom.weave(&FORWARD, &BACKWARD);
+        }
+        loom
+    }
+    
+    #[test]
+    fn replay_history_yields_every_step_in_order() {
+        let loom = woven_loom(12);
+        assert_eq!(loom.replay_history().count(), loom.weave_pattern.len());
+        assert!(loom.replay_history().eq(loom.weave_pattern.iter()));
+        assert!(loom.replay_from(4).eq(loom.weave_pattern[4..].iter()));
+    }
+    
+    #[test]
+    fn trim_history_keeps_the_last_entries() {
+        let mut loom = woven_loom(12);
+        let tail = loom.weave_pattern[7..].to_vec();
+        
+        loom.trim_history(5);
+        assert_eq!(loom.weave_pattern.len(), 5);
+        assert_eq!(loom.weave_pattern, tail);
+        
+        loom.trim_history(10);
+        assert_eq!(loom.weave_pattern.len(), 5);
+    }
+    
+    #[test]
+    fn opposite_looms_phase_lock() {
+        let mut looms = [TimeWeavingLoom::new(&FORWARD), TimeWeavingLoom::new(&BACKWARD)];
+        looms[1].orbital_phase = PI;
+        assert!(phase_coherence(&looms) > 0.9);
+        
+        for _ in 0..100 {
+            phase_lock(&mut looms, 0.1);
+        }
+        
+        assert!(phase_coherence(&looms) < 0.1);
+    }
+    
+    #[test]
+    fn synchronize_phase_wraps_the_short_way() {
+        let mut loom = TimeWeavingLoom::new(&FORWARD);
+        loom.orbital_phase = 0.1;
+        loom.synchronize_phase(TAU - 0.1, 0.05);
+        
+        assert!((loom.orbital_phase - 0.05).abs() < 1e-6);
+    }
+    
+    fn orbiting(commits: u32, revisions: u32) -> TimeWeavingLoom {
+        let mut loom = TimeWeavingLoom::new(&FORWARD);
+        loom.git.commits = commits;
+        loom.mercurial.revisions = revisions;
+        loom
+    }
+    
+    #[test]
+    fn orbital_resonance_reduces_small_ratios() {
+        assert_eq!(orbiting(6, 4).orbital_resonance_ratio(), Some((3, 2)));
+        assert_eq!(orbiting(7, 11).orbital_resonance_ratio(), None);
+        assert_eq!(orbiting(0, 4).orbital_resonance_ratio(), None);
+    }
+    
+    #[test]
+    fn simpler_resonances_are_more_stable() {
+        assert_eq!(orbiting(5, 5).resonance_stability(), 1.0);
+        assert_eq!(orbiting(8, 4).resonance_stability(), 0.5);
+        assert!((orbiting(9, 3).resonance_stability() - 0.33).abs() < 0.01);
+        assert_eq!(orbiting(7, 11).resonance_stability(), 0.0);
+    }
+    
+    /// A loom whose weave norms follow `series`
+    fn loom_tracing(series: &[f32]) -> TimeWeavingLoom {
+        let mut loom = woven_loom(0);
+        loom.weave_pattern = series.iter().map(|&x| [x, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]).collect();
+        loom
+    }
+    
+    #[test]
+    fn monotone_weave_is_smooth() {
+        let series: Vec<f32> = (0..500).map(|i| 1.0 + i as f32 * 0.01).collect();
+        let loom = loom_tracing(&series);
+        
+        assert!((loom.fractal_dimension() - 1.0).abs() < 0.05);
+        assert!(!loom.is_fractal(1.2));
+    }
+    
+    #[test]
+    fn random_walk_weave_is_half_way_to_a_plane() {
+        let mut state = 0x2545_f491u32;
+        let mut position = 100.0f32;
+        let series: Vec<f32> = (0..2000)
+            .map(|_| {
+                // xorshift32 coin flips
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                position += if state & 1 == 0 { 1.0 } else { -1.0 };
+                position
+            })
+            .collect();
+        let loom = loom_tracing(&series);
+        
+        assert!((loom.fractal_dimension() - 1.5).abs() < 0.15, "{}", loom.fractal_dimension());
+        assert!(loom.is_fractal(1.2));
+    }
+    
+    #[test]
+    fn a_loom_is_entangled_with_itself() {
+        let loom = loom_tracing(&[1.0, 3.0, 2.0, 5.0, 4.0, 0.5]);
+        assert!((loom.entanglement_measure(&loom) - 1.0).abs() < 1e-6);
+        
+        let mirrored = loom_tracing(&[5.0, 3.0, 4.0, 1.0, 2.0, 5.5]);
+        assert!((loom.entanglement_measure(&mirrored) + 1.0).abs() < 1e-6);
+    }
+    
+    #[test]
+    fn full_disentangling_leaves_orthogonal_steps() {
+        let mut loom = woven_loom(12);
+        let other = loom_tracing(&[1.0; 12]);
+        loom.disentangle(&other, 1.0);
+        
+        for (step, theirs) in loom.weave_pattern.iter().zip(other.weave_pattern.iter()) {
+            let overlap: f32 = step.iter().zip(theirs.iter()).map(|(a, b)| a * b).sum();
+            assert!(overlap.abs() < 1e-6);
+        }
+    }
+    
+    #[test]
+    fn optimized_weave_beats_the_resting_orbit() {
+        let target = [0.9, 0.8, 0.7, 0.6, 0.5, 0.4, 0.3];
+        let mut resting = woven_loom(0);
+        let woven = resting.weave(&FORWARD, &BACKWARD);
+        let resting_miss = woven.iter()
+            .zip(target.iter())
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum::<f32>()
+            .sqrt();
+        
+        let mut loom = woven_loom(0);
+        let miss = loom.optimize_weave(&FORWARD, &BACKWARD, &target, 200);
+        
+        assert!(miss < resting_miss);
+        assert_eq!(loom.weave_pattern.len(), 1);
+        assert!((0.1..=10.0).contains(&loom.orbital_radius));
+    }
+    
+    #[test]
+    fn kalman_estimate_converges_on_a_steady_weave() {
+        let steady = 2.0;
+        let mut loom = loom_tracing(&[]);
+        
+        let mut errors = Vec::new();
+        for _ in 0..30 {
+            loom.weave_pattern.push([steady, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+            let filtered = loom.kalman_filter_weave(0.5, 1e-4);
+            errors.push((filtered[0] - steady).abs());
+        }
+        
+        assert!(errors.windows(2).all(|pair| pair[1] <= pair[0]));
+        assert!(errors[29] < 0.05);
+        assert!(loom.kalman.covariance[0] < 1.0);
+    }
+    
+    #[test]
+    fn kalman_filter_observes_each_step_once() {
+        let mut loom = loom_tracing(&[1.0, 2.0, 3.0]);
+        let first = loom.kalman_filter_weave(0.5, 1e-4);
+        let covariance = loom.kalman.covariance;
+        
+        // No new weave, no new evidence
+        assert_eq!(loom.kalman_filter_weave(0.5, 1e-4), first);
+        assert_eq!(loom.kalman.covariance, covariance);
+        
+        // Filtering step by step lands where one catch-up call does
+        let mut stepwise = loom_tracing(&[]);
+        for x in [1.0, 2.0, 3.0] {
+            stepwise.weave_pattern.push([x, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+            stepwise.kalman_filter_weave(0.5, 1e-4);
+        }
+        assert_eq!(stepwise.kalman.estimate, first);
+        
+        loom.trim_history(1);
+        assert_eq!(loom.kalman.filtered_steps, 1);
+        assert_eq!(loom.kalman_filter_weave(0.5, 1e-4), first);
+    }
 }
\ No newline at end of file